@@ -21,6 +21,16 @@ pub fn create_backend() {
 
     // Suppress warning.
     let _backend = backend;
+
+    // ANCHOR: create_backend_with_ciphersuite
+    use openmls_traits::types::Ciphersuite;
+    let backend =
+        EidMlsBackend::with_ciphersuite(Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256)
+            .expect("Ciphersuite not supported");
+    // ANCHOR_END: create_backend_with_ciphersuite
+
+    // Suppress warning.
+    let _backend = backend;
 }
 
 pub fn generate_member(
@@ -28,22 +38,12 @@ pub fn generate_member(
     backend: &EidMlsBackend,
 ) -> (EidMlsMember, SignatureKeyPair) {
     // ANCHOR: create_member
-    let ciphersuite = backend.ciphersuite;
-    let (cred_with_key, keypair) = EidMlsClient::create_store_credential(
-        identity,
-        CredentialType::Basic,
-        ciphersuite.signature_algorithm(),
-        backend,
-    )
-    .expect("Failed to create credential");
-
-    let key_package = EidMlsClient::create_store_key_package(
-        ciphersuite,
-        cred_with_key.clone(),
-        backend,
-        &keypair,
-    )
-    .expect("Failed to create key package");
+    let (cred_with_key, keypair) =
+        EidMlsClient::generate_credential(identity, CredentialType::Basic, backend)
+            .expect("Failed to create credential");
+
+    let key_package = EidMlsClient::generate_key_package(cred_with_key.clone(), backend, &keypair)
+        .expect("Failed to create key package");
 
     let member = EidMlsMember::new((key_package, cred_with_key));
     // ANCHOR_END: create_member
@@ -63,7 +63,8 @@ fn book_operations() {
     let (bob, bob_signature_keys) = generate_member("Bob".into(), backend);
 
     // ANCHOR: alice_create_eid
-    let mut alice_client = EidMlsClient::create_eid(&alice, alice_signature_keys, backend).unwrap();
+    let mut alice_client =
+        EidMlsClient::create_eid(None, &alice, alice_signature_keys, backend).unwrap();
     // ANCHOR_END: alice_create_eid
 
     // ANCHOR: create_transcript
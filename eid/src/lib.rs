@@ -77,6 +77,35 @@ pub mod test_helpers {
         cross_sign_evolvement_in
     }
 
+    /// Transfer an evolvement and evolve the transcript and all given clients with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement_out`: The evolvement as created by a client
+    /// * `clients`: The clients that apply the evolvement
+    /// * `transcript`: The transcript
+    /// * `backend`: The EID backend
+    ///
+    /// returns: [EidClient::EvolvementProvider] The transferred evolvement
+    pub fn evolve_all<C: EidClient>(
+        evolvement_out: &C::EvolvementProvider,
+        clients: &mut [&mut C],
+        transcript: &mut C::TranscriptProvider,
+        backend: &C::BackendProvider,
+    ) -> C::EvolvementProvider {
+        let evolvement_in: C::EvolvementProvider = simulate_transfer(evolvement_out);
+
+        transcript
+            .evolve(evolvement_in.clone(), backend)
+            .expect("Failed to add evolvement to transcript");
+        for client in clients.iter_mut() {
+            client
+                .evolve(evolvement_in.clone(), backend)
+                .expect("Failed to evolve");
+        }
+        evolvement_in
+    }
+
     /// Add a member to the Eid and evolve [EidClient] and [EidTranscript].
     /// Then let the new_members client cross-sign and evolve client and transcript.
     ///
@@ -4,13 +4,29 @@ pub use rstest_reuse::{self, *};
 use eid::test_helpers;
 use eid_dummy::eid_dummy_backend::EidDummyBackend;
 pub use eid_dummy::eid_dummy_client::EidDummyClient;
-use eid_mls::eid_mls_backend::EidMlsBackend;
+use eid_dummy::eid_dummy_evolvement::EidDummyEvolvement;
+use eid_mls::eid_mls_backend::{EidMlsBackend, DEFAULT_CIPHERSUITE};
+use eid_mls::eid_mls_client::EidMlsClient;
+use eid_mls::eid_mls_config::EidMlsConfig;
+use eid_mls::eid_mls_evolvement::EidMlsEvolvement;
+use eid_mls::eid_mls_storage::EidMlsFileStorage;
 use eid_traits::backend::EidBackend;
+use eid_traits::buffer::{BufferedClient, BufferedTranscript, EvolvementStatus};
+use eid_traits::checkpoint::{Checkpoint, SignedCheckpoint};
 use eid_traits::client::EidClient;
+use eid_traits::evolvement::{Evolvement, EvolvementKind, EvolvementVerdict};
 use eid_traits::member::Member;
+use eid_traits::merkle::{ConsistencyProof, InclusionProof};
+use eid_traits::proof::EidMembershipProof;
 use eid_traits::transcript::EidTranscript;
-use eid_traits::types::EidError;
-use test_helpers::{add_and_cross_sign, build_transcript, cross_sign, simulate_transfer};
+use eid_traits::types::{
+    ChallengeResponse, EidChange, EidError, EidId, InvitationExpiry, MembershipEvent,
+};
+use openmls_traits::types::Ciphersuite;
+use test_helpers::{
+    add_and_cross_sign, build_transcript, cross_sign, evolve_all, simulate_transfer,
+};
+use tls_codec::Serialize;
 
 #[template]
 #[rstest(backend,
@@ -54,12 +70,12 @@ fn add<B: EidBackend>(backend: &B) {
     let error = client
         .evolve(add_alice_evolvement_in.clone(), backend)
         .expect_err("Evolving with same evolvement twice");
-    assert!(matches!(error, EidError::InvalidEvolvementError(..)));
+    assert!(matches!(error, EidError::StaleEvolvementError(..)));
 
     let error = transcript
         .evolve(add_alice_evolvement_in, backend)
         .expect_err("Evolving with same evolvement twice");
-    assert!(matches!(error, EidError::InvalidEvolvementError(..)));
+    assert!(matches!(error, EidError::StaleEvolvementError(..)));
 
     let members_after_alice_cross_sign = client.get_members();
     assert!(members_after_alice_cross_sign.contains(&alice));
@@ -148,6 +164,143 @@ fn remove<B: EidBackend>(backend: &B) {
     assert_eq!(1, members.len());
 }
 
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership
+/// * create and add a second member to the EID
+/// * let the second member leave the EID
+/// * let the first member commit the leave proposal
+/// * let the second member evolve and try every change to the EID, expecting Errors since it is inactive
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[apply(eid_backends)]
+fn leave<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&add_alice_evolvement_out);
+    transcript
+        .evolve(add_alice_evolvement_in.clone(), backend)
+        .expect("Failed to add evolvement to transcript");
+    client
+        .evolve(add_alice_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    assert_eq!(2, client.get_members().len());
+
+    // Alice proposes to leave, the member list is unchanged until the proposal is committed
+    let leave_evolvement_out = alice_client.leave(backend).expect("failed to leave");
+    let leave_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&leave_evolvement_out);
+    transcript
+        .evolve(leave_evolvement_in.clone(), backend)
+        .expect("Failed to add leave evolvement to transcript");
+    client
+        .evolve(leave_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(leave_evolvement_in, backend)
+        .expect("Failed to evolve");
+    assert_eq!(2, client.get_members().len());
+    assert_eq!(transcript.get_members(), client.get_members());
+    assert!(alice_client.is_active());
+
+    let commit_evolvement_out = client
+        .commit_pending_proposals(backend)
+        .expect("failed to commit pending proposals");
+    let commit_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&commit_evolvement_out);
+    transcript
+        .evolve(commit_evolvement_in.clone(), backend)
+        .expect("Failed to evolve transcript");
+    client
+        .evolve(commit_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(commit_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(!members.contains(&alice));
+    assert_eq!(1, members.len());
+    assert_eq!(transcript.get_members(), members);
+
+    assert!(client.is_active());
+    assert!(!alice_client.is_active());
+    let error = alice_client
+        .evolve(commit_evolvement_in, backend)
+        .expect_err("Evolving an inactive client");
+    assert!(matches!(error, EidError::InactiveClientError(..)));
+    let error = alice_client
+        .update(backend)
+        .expect_err("Updating an inactive client");
+    assert!(matches!(error, EidError::InactiveClientError(..)));
+    let (bob, _) = B::ClientProvider::generate_member("bob".into(), backend);
+    let error = alice_client
+        .add(&bob, backend)
+        .expect_err("Adding a member with an inactive client");
+    assert!(matches!(error, EidError::InactiveClientError(..)));
+    let member = &members[0];
+    let change = EidChange {
+        added: vec![bob.clone()],
+        removed: vec![],
+        self_update: false,
+    };
+    let results = [
+        ("Removing a member", alice_client.remove(member, backend)),
+        (
+            "Removing members",
+            alice_client.remove_many(std::slice::from_ref(member), backend),
+        ),
+        (
+            "Replacing a member",
+            alice_client.replace(member, &bob, backend),
+        ),
+        ("Leaving", alice_client.leave(backend)),
+        ("Proposing an add", alice_client.propose_add(&bob, backend)),
+        (
+            "Proposing a remove",
+            alice_client.propose_remove(member, backend),
+        ),
+        ("Proposing an update", alice_client.propose_update(backend)),
+        (
+            "Committing pending proposals",
+            alice_client.commit_pending_proposals(backend),
+        ),
+        ("Cross-signing", alice_client.cross_sign_membership(backend)),
+        (
+            "Re-creating a change",
+            alice_client.recreate(&change, backend),
+        ),
+    ];
+    for (operation, result) in results {
+        let error = result.expect_err(operation);
+        assert!(
+            matches!(error, EidError::InactiveClientError(..)),
+            "{}: {:?}",
+            operation,
+            error
+        );
+    }
+
+    let error = client
+        .commit_pending_proposals(backend)
+        .expect_err("Committing without pending proposals");
+    assert!(matches!(error, EidError::CommitError(..)));
+}
+
 /// This test simulates the following:
 /// * create a new client
 /// * create a transcript from the client's state
@@ -207,3 +360,2781 @@ fn update<B: EidBackend>(backend: &B) {
     );
     assert_eq!(1, members_after_update_2.len());
 }
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership
+/// * add and remove no members, expecting an Error
+/// * add two members with a single evolvement
+/// * let both members cross sign their membership
+/// * remove both members with a single evolvement
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[apply(eid_backends)]
+fn add_remove_many<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let error = client
+        .add_many(&[], backend)
+        .err()
+        .expect("Adding no members");
+    assert!(matches!(error, EidError::AddMemberError(..)));
+    let error = client
+        .remove_many(&[], backend)
+        .err()
+        .expect("Removing no members");
+    assert!(matches!(error, EidError::RemoveMemberError(..)));
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+
+    let log_length = transcript.log().len();
+    let add_evolvement_out = client
+        .add_many(&[alice.clone(), bob.clone()], backend)
+        .expect("failed to add members");
+    let add_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&add_evolvement_out);
+    transcript
+        .evolve(add_evolvement_in.clone(), backend)
+        .expect("Failed to add evolvement to transcript");
+    client
+        .evolve(add_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+    assert_eq!(log_length + 1, transcript.log().len());
+
+    let alice_client = &mut B::ClientProvider::create_from_invitation(
+        add_evolvement_in.clone(),
+        alice_kp,
+        backend,
+    )
+    .expect("failed to create client from invitation");
+    let bob_client =
+        &mut B::ClientProvider::create_from_invitation(add_evolvement_in, bob_kp, backend)
+            .expect("failed to create client from invitation");
+
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+    bob_client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let bob_cross_sign_in = cross_sign(bob_client, &mut transcript, backend);
+    client
+        .evolve(bob_cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(members.contains(&alice));
+    assert!(members.contains(&bob));
+    assert_eq!(3, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    assert_eq!(bob_client.get_members(), members);
+
+    let removed_members: Vec<_> = members
+        .into_iter()
+        .filter(|member| member == &alice || member == &bob)
+        .collect();
+    let log_length = transcript.log().len();
+    let remove_evolvement_out = client
+        .remove_many(&removed_members, backend)
+        .expect("failed to remove members");
+    let remove_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&remove_evolvement_out);
+    transcript
+        .evolve(remove_evolvement_in.clone(), backend)
+        .expect("Failed to evolve transcript");
+    client
+        .evolve(remove_evolvement_in, backend)
+        .expect("Failed to evolve");
+    assert_eq!(log_length + 1, transcript.log().len());
+
+    let members = client.get_members();
+    assert!(!members.contains(&alice));
+    assert!(!members.contains(&bob));
+    assert_eq!(1, members.len());
+    assert_eq!(transcript.get_members(), members);
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership
+/// * create and add a second member to the EID
+/// * replace the second member by a third member with a single evolvement
+/// * let the third member cross sign its membership
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[apply(eid_backends)]
+fn replace<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    add_and_cross_sign(client, &mut transcript, alice.clone(), alice_kp, backend);
+
+    let alice_after_insert = client
+        .get_members()
+        .into_iter()
+        .find(|member| member.clone() == alice)
+        .expect("Alice not found");
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+
+    let log_length = transcript.log().len();
+    let replace_evolvement_out = client
+        .replace(&alice_after_insert, &bob, backend)
+        .expect("failed to replace member");
+    let replace_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&replace_evolvement_out);
+    transcript
+        .evolve(replace_evolvement_in.clone(), backend)
+        .expect("Failed to evolve transcript");
+    client
+        .evolve(replace_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+    assert_eq!(log_length + 1, transcript.log().len());
+
+    let members = client.get_members();
+    assert!(!members.contains(&alice));
+    assert_eq!(1, members.len());
+    assert_eq!(transcript.get_members(), members);
+
+    let bob_client =
+        &mut B::ClientProvider::create_from_invitation(replace_evolvement_in, bob_kp, backend)
+            .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob_client, &mut transcript, backend);
+    client
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(members.contains(&bob));
+    assert_eq!(2, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(bob_client.get_members(), members);
+
+    // The replacement isn't mixed with pending proposals
+    let (carol, _) = B::ClientProvider::generate_member("carol".into(), backend);
+    let (dave, _) = B::ClientProvider::generate_member("dave".into(), backend);
+    let propose_carol_out = client
+        .propose_add(&carol, backend)
+        .expect("failed to propose member");
+    evolve_all(
+        &propose_carol_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let bob_after_insert = client
+        .get_members()
+        .into_iter()
+        .find(|member| member.clone() == bob)
+        .expect("Bob not found");
+    let error = client
+        .replace(&bob_after_insert, &dave, backend)
+        .err()
+        .expect("Replacing with pending proposals");
+    assert!(matches!(error, EidError::CommitError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership
+/// * let the client propose adding a second member and commit the proposal
+/// * let the second member propose adding a third member and let the client commit the proposal
+/// * let the second member propose an update and let the third member commit the proposal
+/// * let the client propose removing the third member and let the second member commit the proposal
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[apply(eid_backends)]
+fn proposals<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    // The client proposes and commits Alice's addition
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let propose_alice_out = client
+        .propose_add(&alice, backend)
+        .expect("failed to propose adding member");
+    evolve_all(
+        &propose_alice_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    assert_eq!(1, client.get_members().len());
+
+    let commit_out = client
+        .commit_pending_proposals(backend)
+        .expect("failed to commit pending proposals");
+    let commit_in = evolve_all(&commit_out, &mut [&mut *client], &mut transcript, backend);
+    let alice_client = &mut B::ClientProvider::create_from_invitation(commit_in, alice_kp, backend)
+        .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    assert_eq!(2, client.get_members().len());
+    assert_eq!(transcript.get_members(), client.get_members());
+
+    // Alice proposes Bob's addition, the client commits it
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let propose_bob_out = alice_client
+        .propose_add(&bob, backend)
+        .expect("failed to propose adding member");
+    evolve_all(
+        &propose_bob_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    let commit_out = client
+        .commit_pending_proposals(backend)
+        .expect("failed to commit pending proposals");
+    let commit_in = evolve_all(
+        &commit_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    let bob_client = &mut B::ClientProvider::create_from_invitation(commit_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob_client, &mut transcript, backend);
+    client
+        .evolve(bob_cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(members.contains(&alice));
+    assert!(members.contains(&bob));
+    assert_eq!(3, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    assert_eq!(bob_client.get_members(), members);
+
+    // Alice proposes to update her key material, Bob commits it
+    let alice_before_update = members
+        .into_iter()
+        .find(|member| member == &alice)
+        .expect("Alice not found");
+    let propose_update_out = alice_client
+        .propose_update(backend)
+        .expect("failed to propose update");
+    evolve_all(
+        &propose_update_out,
+        &mut [&mut *client, &mut *alice_client, &mut *bob_client],
+        &mut transcript,
+        backend,
+    );
+    let commit_out = bob_client
+        .commit_pending_proposals(backend)
+        .expect("failed to commit pending proposals");
+    evolve_all(
+        &commit_out,
+        &mut [&mut *client, &mut *alice_client, &mut *bob_client],
+        &mut transcript,
+        backend,
+    );
+
+    let members = client.get_members();
+    assert_eq!(3, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    let alice_after_update = members
+        .into_iter()
+        .find(|member| member == &alice)
+        .expect("Alice not found");
+    assert_ne!(alice_before_update.get_pk(), alice_after_update.get_pk());
+
+    // The client proposes Bob's removal, Alice commits it
+    let bob_member = client
+        .get_members()
+        .into_iter()
+        .find(|member| member == &bob)
+        .expect("Bob not found");
+    let propose_remove_out = client
+        .propose_remove(&bob_member, backend)
+        .expect("failed to propose removing member");
+    evolve_all(
+        &propose_remove_out,
+        &mut [&mut *client, &mut *alice_client, &mut *bob_client],
+        &mut transcript,
+        backend,
+    );
+    let commit_out = alice_client
+        .commit_pending_proposals(backend)
+        .expect("failed to commit pending proposals");
+    evolve_all(
+        &commit_out,
+        &mut [&mut *client, &mut *alice_client, &mut *bob_client],
+        &mut transcript,
+        backend,
+    );
+
+    let members = client.get_members();
+    assert!(!members.contains(&bob));
+    assert_eq!(2, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    assert!(!bob_client.is_active());
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * let the client update its key material twice, without the second member evolving
+/// * let the second member evolve with the second update, expecting an Error since it is from the future
+/// * let the second member evolve with both updates in order
+/// * let the second member evolve with the first update again, expecting an Error since it is stale
+///
+/// We evolve clients and transcript along the way, comparing their epochs
+#[apply(eid_backends)]
+fn epochs<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+    assert_eq!(client.epoch(), transcript.epoch());
+
+    let epoch_before_cross_sign = client.epoch();
+    let cross_sign_in = cross_sign(client, &mut transcript, backend);
+    assert_eq!(epoch_before_cross_sign, cross_sign_in.target_epoch());
+    assert_eq!(epoch_before_cross_sign + 1, client.epoch());
+    assert_eq!(client.epoch(), transcript.epoch());
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in = evolve_all(
+        &add_alice_evolvement_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    assert_eq!(client.epoch(), alice_client.epoch());
+    assert_eq!(client.epoch(), transcript.epoch());
+
+    let epoch_before_updates = client.epoch();
+    let update_1_out = client.update(backend).expect("Updating client keys failed");
+    let update_1_in = evolve_all(&update_1_out, &mut [&mut *client], &mut transcript, backend);
+    let update_2_out = client.update(backend).expect("Updating client keys failed");
+    let update_2_in = evolve_all(&update_2_out, &mut [&mut *client], &mut transcript, backend);
+    assert_eq!(epoch_before_updates, update_1_in.target_epoch());
+    assert_eq!(epoch_before_updates + 1, update_2_in.target_epoch());
+    assert_eq!(epoch_before_updates + 2, client.epoch());
+    assert_eq!(client.epoch(), transcript.epoch());
+
+    let error = alice_client
+        .evolve(update_2_in.clone(), backend)
+        .expect_err("Evolving with an evolvement from the future");
+    assert!(matches!(error, EidError::FutureEvolvementError(..)));
+    assert_eq!(epoch_before_updates, alice_client.epoch());
+
+    alice_client
+        .evolve(update_1_in.clone(), backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(update_2_in, backend)
+        .expect("Failed to evolve");
+    assert_eq!(client.epoch(), alice_client.epoch());
+    assert_eq!(client.get_members(), alice_client.get_members());
+
+    let error = alice_client
+        .evolve(update_1_in.clone(), backend)
+        .expect_err("Evolving with a stale evolvement");
+    assert!(matches!(error, EidError::StaleEvolvementError(..)));
+    let error = transcript
+        .evolve(update_1_in, backend)
+        .expect_err("Evolving with a stale evolvement");
+    assert!(matches!(error, EidError::StaleEvolvementError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * wrap the second member's client and a second transcript in a reordering buffer
+/// * let the client update its key material three times
+/// * check that a buffer with a distance of one epoch rejects the last update
+/// * deliver the updates to the buffered client and transcript in reverse order and with duplicates
+///
+/// We compare the missing epochs and the states of the buffered client and transcript along the way
+#[apply(eid_backends)]
+fn buffered_evolvements<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in = evolve_all(
+        &add_alice_evolvement_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let mut alice_client =
+        B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(&mut alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let mut buffered_transcript = BufferedTranscript::new(build_transcript(client, backend));
+    let mut near_transcript =
+        BufferedTranscript::with_max_distance(build_transcript(client, backend), 1);
+    let mut buffered_alice = BufferedClient::new(alice_client);
+    let epoch = buffered_alice.client().epoch();
+    assert_eq!(epoch, buffered_transcript.transcript().epoch());
+
+    let updates: Vec<_> = (0..3)
+        .map(|_| {
+            let update_out = client.update(backend).expect("Updating client keys failed");
+            evolve_all(&update_out, &mut [&mut *client], &mut transcript, backend)
+        })
+        .collect();
+
+    // Evolvements further ahead than the buffer's distance are rejected
+    assert!(matches!(
+        near_transcript.evolve(updates[2].clone(), backend),
+        Err(EidError::FutureEvolvementError(_))
+    ));
+    assert!(near_transcript.buffer().is_empty());
+
+    // Deliver the updates in reverse order, the last two are buffered
+    let status = buffered_alice
+        .evolve(updates[2].clone(), backend)
+        .expect("Failed to buffer evolvement");
+    assert_eq!(EvolvementStatus::Buffered, status);
+    assert_eq!(vec![epoch, epoch + 1], buffered_alice.missing_epochs());
+
+    let status = buffered_alice
+        .evolve(updates[1].clone(), backend)
+        .expect("Failed to buffer evolvement");
+    assert_eq!(EvolvementStatus::Buffered, status);
+    assert_eq!(vec![epoch], buffered_alice.missing_epochs());
+
+    // Duplicates of buffered evolvements are dropped
+    let status = buffered_alice
+        .evolve(updates[1].clone(), backend)
+        .expect("Failed to drop evolvement");
+    assert_eq!(EvolvementStatus::Dropped, status);
+    assert_eq!(2, buffered_alice.buffer().len());
+    assert_eq!(epoch, buffered_alice.client().epoch());
+
+    // Filling the gap applies all buffered evolvements
+    let status = buffered_alice
+        .evolve(updates[0].clone(), backend)
+        .expect("Failed to evolve");
+    assert_eq!(
+        EvolvementStatus::Applied {
+            buffered: 2,
+            failed: vec![]
+        },
+        status
+    );
+    assert!(buffered_alice.missing_epochs().is_empty());
+    assert!(buffered_alice.buffer().is_empty());
+    assert_eq!(client.epoch(), buffered_alice.client().epoch());
+    assert_eq!(client.get_members(), buffered_alice.client().get_members());
+
+    // Duplicates of applied evolvements are dropped
+    let status = buffered_alice
+        .evolve(updates[0].clone(), backend)
+        .expect("Failed to drop evolvement");
+    assert_eq!(EvolvementStatus::Dropped, status);
+
+    // The transcript reorders the same way
+    buffered_transcript
+        .batch_evolve(updates.into_iter().rev().collect(), backend)
+        .expect("Failed to evolve transcript");
+    assert!(buffered_transcript.missing_epochs().is_empty());
+    assert_eq!(transcript.epoch(), buffered_transcript.transcript().epoch());
+    assert_eq!(
+        transcript.get_members(),
+        buffered_transcript.transcript().get_members()
+    );
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * let both members update their key material in the same epoch, the client's update wins
+/// * let the second member resolve the conflict and re-create its update
+/// * let both members change the member list in the same epoch, the client's update wins again
+/// * let the second member resolve the conflict with a stale evolvement, expecting an Error and no state change
+/// * let the second member resolve the conflict and re-create the addition of a third member
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[apply(eid_backends)]
+fn conflicts<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in = evolve_all(
+        &add_alice_evolvement_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    assert!(client.pending_change().is_none());
+    assert!(alice_client.pending_change().is_none());
+
+    // Both members update, the client's update reaches the transcript first
+    let alice_before_update = alice_client
+        .get_members()
+        .into_iter()
+        .find(|member| member == &alice)
+        .expect("Alice not found");
+    let client_update_out = client.update(backend).expect("Updating client keys failed");
+    alice_client
+        .update(backend)
+        .expect("Updating client keys failed");
+    let pending_change = alice_client
+        .pending_change()
+        .expect("Alice has no pending change");
+    assert!(pending_change.self_update);
+
+    let client_update_in = evolve_all(
+        &client_update_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let error = alice_client
+        .evolve(client_update_in.clone(), backend)
+        .expect_err("Evolving with a competing evolvement");
+    assert!(matches!(error, EidError::ConflictingEvolvementError(..)));
+
+    let change = alice_client
+        .resolve_conflict(client_update_in, backend)
+        .expect("Failed to resolve conflict")
+        .expect("Alice had no pending change");
+    assert_eq!(pending_change, change);
+    assert!(alice_client.pending_change().is_none());
+    assert_eq!(client.epoch(), alice_client.epoch());
+
+    let recreated_update_out = alice_client
+        .recreate(&change, backend)
+        .expect("Failed to re-create update");
+    evolve_all(
+        &recreated_update_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    let members = client.get_members();
+    assert_eq!(2, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    let alice_after_update = members
+        .into_iter()
+        .find(|member| member == &alice)
+        .expect("Alice not found");
+    assert_ne!(alice_before_update.get_pk(), alice_after_update.get_pk());
+
+    // Alice adds Bob while the client updates, the client's update wins
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let client_update_out = client.update(backend).expect("Updating client keys failed");
+    alice_client
+        .add(&bob, backend)
+        .expect("failed to add member");
+    let client_update_in = evolve_all(
+        &client_update_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    // Resolving with an evolvement that fails keeps the pending change
+    let stale_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&recreated_update_out);
+    alice_client
+        .resolve_conflict(stale_in, backend)
+        .expect_err("Resolving a conflict with a stale evolvement");
+    let pending_change = alice_client
+        .pending_change()
+        .expect("Alice lost her pending change");
+    assert_eq!(vec![bob.clone()], pending_change.added);
+    let change = alice_client
+        .resolve_conflict(client_update_in, backend)
+        .expect("Failed to resolve conflict")
+        .expect("Alice had no pending change");
+    assert_eq!(vec![bob.clone()], change.added);
+    assert!(change.removed.is_empty());
+
+    let recreated_add_out = alice_client
+        .recreate(&change, backend)
+        .expect("Failed to re-create addition");
+    let recreated_add_in = evolve_all(
+        &recreated_add_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    let bob_client =
+        &mut B::ClientProvider::create_from_invitation(recreated_add_in, bob_kp, backend)
+            .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob_client, &mut transcript, backend);
+    client
+        .evolve(bob_cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(members.contains(&bob));
+    assert_eq!(3, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    assert_eq!(bob_client.get_members(), members);
+
+    // Nothing is left to re-create once Bob is a member
+    let error = alice_client
+        .recreate(&change, backend)
+        .expect_err("Re-creating an applied change");
+    assert!(matches!(error, EidError::CommitError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * let the client update its key material twice, without evolving the second member and the transcript
+/// * batch evolve the second member and the transcript with both updates and a stale evolvement in between,
+///   expecting an Error and no state change
+/// * batch evolve the second member and the transcript with both updates
+///
+/// We compare the states of clients and transcript along the way
+#[apply(eid_backends)]
+fn atomic_batch_evolve<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in = evolve_all(
+        &add_alice_evolvement_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+
+    let mut updates = vec![];
+    for _ in 0..2 {
+        let update_out = client.update(backend).expect("Updating client keys failed");
+        let update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+            simulate_transfer(&update_out);
+        client
+            .evolve(update_in.clone(), backend)
+            .expect("Failed to evolve");
+        updates.push(update_in);
+    }
+
+    let epoch = alice_client.epoch();
+    let members = alice_client.get_members();
+    let log_length = transcript.log().len();
+    let bad_batch = vec![updates[0].clone(), alice_cross_sign_in, updates[1].clone()];
+
+    let error = alice_client
+        .batch_evolve(bad_batch.clone(), backend)
+        .expect_err("Evolving with a stale evolvement in the batch");
+    assert!(matches!(error, EidError::StaleEvolvementError(..)));
+    assert_eq!(epoch, alice_client.epoch());
+    assert_eq!(members, alice_client.get_members());
+
+    let error = transcript
+        .batch_evolve(bad_batch, backend)
+        .expect_err("Evolving with a stale evolvement in the batch");
+    assert!(matches!(error, EidError::StaleEvolvementError(..)));
+    assert_eq!(epoch, transcript.epoch());
+    assert_eq!(members, transcript.get_members());
+    assert_eq!(log_length, transcript.log().len());
+
+    // The rolled back client and transcript can still apply the updates
+    alice_client
+        .batch_evolve(updates.clone(), backend)
+        .expect("Failed to evolve");
+    transcript
+        .batch_evolve(updates, backend)
+        .expect("Failed to evolve transcript");
+    assert_eq!(client.epoch(), alice_client.epoch());
+    assert_eq!(client.epoch(), transcript.epoch());
+    assert_eq!(log_length + 2, transcript.log().len());
+    assert_eq!(client.get_members(), alice_client.get_members());
+    assert_eq!(client.get_members(), transcript.get_members());
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * let the client update its key material twice, without evolving the second member and the transcript
+/// * validate both updates with the second member and the transcript, expecting the first to be valid and
+///   the second to apply to a future epoch, without any state change
+/// * evolve the second member and the transcript with the first update and validate it again, expecting it to be stale
+///
+/// We compare the states of clients and transcript along the way
+#[apply(eid_backends)]
+fn validate<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in = evolve_all(
+        &add_alice_evolvement_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let mut updates = vec![];
+    for _ in 0..2 {
+        let update_out = client.update(backend).expect("Updating client keys failed");
+        let update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+            simulate_transfer(&update_out);
+        client
+            .evolve(update_in.clone(), backend)
+            .expect("Failed to evolve");
+        updates.push(update_in);
+    }
+
+    let epoch = alice_client.epoch();
+    let members = alice_client.get_members();
+    let log_length = transcript.log().len();
+
+    assert_eq!(
+        EvolvementVerdict::Valid,
+        alice_client.validate(&updates[0], backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::Valid,
+        transcript.validate(&updates[0], backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::Future,
+        alice_client.validate(&updates[1], backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::Future,
+        transcript.validate(&updates[1], backend)
+    );
+
+    // Validating doesn't change the client or the transcript
+    assert_eq!(epoch, alice_client.epoch());
+    assert_eq!(members, alice_client.get_members());
+    assert_eq!(epoch, transcript.epoch());
+    assert_eq!(members, transcript.get_members());
+    assert_eq!(log_length, transcript.log().len());
+
+    alice_client
+        .evolve(updates[0].clone(), backend)
+        .expect("Failed to evolve");
+    transcript
+        .evolve(updates[0].clone(), backend)
+        .expect("Failed to evolve transcript");
+
+    assert_eq!(
+        EvolvementVerdict::Stale,
+        alice_client.validate(&updates[0], backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::Stale,
+        transcript.validate(&updates[0], backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::Valid,
+        alice_client.validate(&updates[1], backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::Valid,
+        transcript.validate(&updates[1], backend)
+    );
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership, add a second member, update its key material
+///   and remove the second member again
+/// * let the second member cross sign its membership
+///
+/// Before each evolvement is applied, the client and the transcript describe it.
+/// We compare the descriptions with the expected kind, sender, added and removed members and epoch.
+#[apply(eid_backends)]
+fn describe<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    let cross_sign_out = client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let cross_sign_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&cross_sign_out);
+    let summary = transcript
+        .describe(&cross_sign_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(summary, client.describe(&cross_sign_in, backend).unwrap());
+    assert_eq!(EvolvementKind::CrossSign, summary.kind);
+    assert!(summary.added.is_empty());
+    assert!(summary.removed.is_empty());
+    assert_eq!(transcript.epoch() + 1, summary.epoch);
+    transcript
+        .evolve(cross_sign_in.clone(), backend)
+        .expect("Failed to evolve transcript");
+    client
+        .evolve(cross_sign_in, backend)
+        .expect("Failed to evolve");
+    let myself = client.get_members().pop();
+    assert_eq!(myself, summary.sender);
+    assert_eq!(transcript.epoch(), summary.epoch);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&add_alice_out);
+    let summary = transcript
+        .describe(&add_alice_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(summary, client.describe(&add_alice_in, backend).unwrap());
+    assert_eq!(EvolvementKind::Add, summary.kind);
+    assert_eq!(myself, summary.sender);
+    assert_eq!(vec![alice.clone()], summary.added);
+    assert!(summary.removed.is_empty());
+    assert_eq!(transcript.epoch() + 1, summary.epoch);
+    evolve_all(
+        &add_alice_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_out = alice_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let alice_cross_sign_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&alice_cross_sign_out);
+    let summary = client
+        .describe(&alice_cross_sign_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(EvolvementKind::CrossSign, summary.kind);
+    assert_eq!(Some(alice.clone()), summary.sender);
+    evolve_all(
+        &alice_cross_sign_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+
+    let update_out = client.update(backend).expect("Updating client keys failed");
+    let update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&update_out);
+    let summary = alice_client
+        .describe(&update_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(summary, transcript.describe(&update_in, backend).unwrap());
+    assert_eq!(EvolvementKind::Update, summary.kind);
+    assert_eq!(myself, summary.sender);
+    assert!(summary.added.is_empty());
+    assert!(summary.removed.is_empty());
+    evolve_all(
+        &update_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+
+    let remove_alice_out = client
+        .remove(&alice, backend)
+        .expect("failed to remove member");
+    let remove_alice_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&remove_alice_out);
+    let summary = transcript
+        .describe(&remove_alice_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(EvolvementKind::Remove, summary.kind);
+    assert_eq!(myself, summary.sender);
+    assert!(summary.added.is_empty());
+    assert_eq!(vec![alice], summary.removed);
+    assert_eq!(transcript.epoch() + 1, summary.epoch);
+
+    // Describing doesn't change the transcript
+    assert_eq!(2, transcript.get_members().len());
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * let the second member cross sign its membership
+/// * let the client update its key material and remove the second member
+///
+/// We compare the membership events reported by the clients and the transcript along the way
+#[apply(eid_backends)]
+fn membership_events<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    let cross_sign_out = client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let cross_sign_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&cross_sign_out);
+    let events = client
+        .evolve(cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+    let myself = client.get_members().pop().expect("Client is a member");
+    assert_eq!(
+        vec![MembershipEvent::MemberCrossSigned(myself.clone())],
+        events
+    );
+    assert_eq!(
+        events,
+        transcript
+            .evolve(cross_sign_in, backend)
+            .expect("Failed to evolve transcript")
+    );
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&add_alice_out);
+    let events = client
+        .evolve(add_alice_in.clone(), backend)
+        .expect("Failed to evolve");
+    assert!(events.contains(&MembershipEvent::MemberAdded(alice.clone())));
+    assert!(!events.contains(&MembershipEvent::MemberCrossSigned(alice.clone())));
+    assert_eq!(
+        events,
+        transcript
+            .evolve(add_alice_in.clone(), backend)
+            .expect("Failed to evolve transcript")
+    );
+
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let alice_cross_sign_out = alice_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let alice_cross_sign_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&alice_cross_sign_out);
+    let events = alice_client
+        .evolve(alice_cross_sign_in.clone(), backend)
+        .expect("Failed to evolve");
+    assert_eq!(
+        vec![MembershipEvent::MemberCrossSigned(alice.clone())],
+        events
+    );
+    assert_eq!(
+        events,
+        client
+            .evolve(alice_cross_sign_in.clone(), backend)
+            .expect("Failed to evolve")
+    );
+    assert_eq!(
+        events,
+        transcript
+            .evolve(alice_cross_sign_in, backend)
+            .expect("Failed to evolve transcript")
+    );
+
+    let update_out = client.update(backend).expect("Updating client keys failed");
+    let update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&update_out);
+    client
+        .evolve(update_in.clone(), backend)
+        .expect("Failed to evolve");
+    let remove_alice_out = client
+        .remove(&alice, backend)
+        .expect("failed to remove member");
+    let remove_alice_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&remove_alice_out);
+    client
+        .evolve(remove_alice_in.clone(), backend)
+        .expect("Failed to evolve");
+
+    // A batch reports the events of all evolvements in order
+    let events = transcript
+        .batch_evolve(vec![update_in, remove_alice_in], backend)
+        .expect("Failed to evolve transcript");
+    assert_eq!(
+        Some(&MembershipEvent::MemberUpdatedKey(myself.clone())),
+        events.first()
+    );
+    assert!(events.contains(&MembershipEvent::MemberRemoved(alice)));
+    assert_eq!(vec![myself], transcript.get_members());
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member, which is pending until it cross signs
+/// * let the second member cross sign its membership
+/// * add a third member and revoke its invitation before it cross signs
+/// * revoke the invitation of the second member and the third member again, expecting an Error
+///
+/// We compare the members and pending members of clients and transcript along the way
+#[apply(eid_backends)]
+fn pending_members<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+    assert!(client.get_pending_members().is_empty());
+    assert!(transcript.get_pending_members().is_empty());
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_in = evolve_all(
+        &add_alice_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+
+    // Alice is invited, but not a member yet
+    assert_eq!(vec![alice.clone()], client.get_pending_members());
+    assert_eq!(vec![alice.clone()], transcript.get_pending_members());
+    assert!(!client.get_members().contains(&alice));
+    assert!(!transcript.get_members().contains(&alice));
+
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    assert_eq!(vec![alice.clone()], alice_client.get_pending_members());
+
+    let alice_cross_sign_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(alice_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    assert!(client.get_pending_members().is_empty());
+    assert!(alice_client.get_pending_members().is_empty());
+    assert!(transcript.get_pending_members().is_empty());
+    assert!(client.get_members().contains(&alice));
+    assert_eq!(transcript.get_members(), client.get_members());
+
+    // Revoke Bob's invitation before he cross signs
+    let (bob, _bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_bob_out = client.add(&bob, backend).expect("failed to add member");
+    evolve_all(
+        &add_bob_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    assert_eq!(vec![bob.clone()], alice_client.get_pending_members());
+
+    let revoke_bob_out = alice_client
+        .revoke_invitation(&bob, backend)
+        .expect("failed to revoke invitation");
+    evolve_all(
+        &revoke_bob_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    assert!(client.get_pending_members().is_empty());
+    assert!(transcript.get_pending_members().is_empty());
+    assert_eq!(2, client.get_members().len());
+    assert_eq!(transcript.get_members(), client.get_members());
+
+    // Only pending invitations can be revoked
+    let error = client
+        .revoke_invitation(&alice, backend)
+        .expect_err("Revoking the invitation of a cross signed member");
+    assert!(matches!(error, EidError::InvalidMemberError(..)));
+    let error = client
+        .revoke_invitation(&bob, backend)
+        .expect_err("Revoking a revoked invitation");
+    assert!(matches!(error, EidError::InvalidMemberError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript
+/// * invite Alice with an invitation that expires after three epochs
+/// * let the client update its key material until the invitation has expired
+/// * reject Alice's late cross sign, when validating it and when applying it
+/// * invite Bob with an invitation that expires at a given time
+/// * let Bob join and learn Alice's invitation
+/// * prune both expired invitations
+#[apply(eid_backends)]
+fn invitation_expiry<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let expiry = InvitationExpiry::Epoch(client.epoch() + 3);
+    let add_alice_out = client
+        .add_with_expiry(&alice, expiry, backend)
+        .expect("failed to add member");
+    let add_alice_in = evolve_all(
+        &add_alice_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    assert!(client.get_expired_members(None).is_empty());
+    assert!(transcript.get_expired_members(None).is_empty());
+
+    for _ in 0..2 {
+        let update_out = client.update(backend).expect("failed to update");
+        evolve_all(
+            &update_out,
+            &mut [&mut *client, &mut *alice_client],
+            &mut transcript,
+            backend,
+        );
+    }
+    assert_eq!(vec![alice.clone()], client.get_expired_members(None));
+    assert_eq!(vec![alice.clone()], transcript.get_expired_members(None));
+
+    // Alice's invitation has expired, so she can't cross sign anymore
+    let alice_cross_sign_out = alice_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let alice_cross_sign_in: B::EvolvementProvider = simulate_transfer(&alice_cross_sign_out);
+    assert!(matches!(
+        transcript.validate(&alice_cross_sign_in, backend),
+        EvolvementVerdict::Invalid(_)
+    ));
+    assert!(matches!(
+        client.validate(&alice_cross_sign_in, backend),
+        EvolvementVerdict::Invalid(_)
+    ));
+    let error = transcript
+        .evolve(alice_cross_sign_in.clone(), backend)
+        .expect_err("Accepted the cross sign of an expired invitation");
+    assert!(matches!(error, EidError::InvalidEvolvementError(..)));
+    let error = client
+        .evolve(alice_cross_sign_in, backend)
+        .expect_err("Accepted the cross sign of an expired invitation");
+    assert!(matches!(error, EidError::InvalidEvolvementError(..)));
+    assert_eq!(vec![alice.clone()], client.get_pending_members());
+
+    // Bob's invitation expires at a time supplied by the caller
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_bob_out = client
+        .add_with_expiry(&bob, InvitationExpiry::Time(100), backend)
+        .expect("failed to add member");
+    let add_bob_in = evolve_all(&add_bob_out, &mut [&mut *client], &mut transcript, backend);
+    let bob_client = &mut B::ClientProvider::create_from_invitation(add_bob_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    // Bob learns the invitations of the other pending members when joining
+    assert_eq!(vec![alice.clone()], bob_client.get_expired_members(None));
+    assert_eq!(
+        client.get_expired_members(Some(100)),
+        bob_client.get_expired_members(Some(100))
+    );
+    assert_eq!(vec![alice.clone()], client.get_expired_members(None));
+    assert_eq!(vec![alice.clone()], client.get_expired_members(Some(99)));
+    let expired_members = client.get_expired_members(Some(100));
+    assert_eq!(2, expired_members.len());
+    assert!(expired_members.contains(&bob));
+    assert_eq!(expired_members, transcript.get_expired_members(Some(100)));
+
+    let prune_out = client
+        .prune_expired_members(Some(100), backend)
+        .expect("failed to prune expired members");
+    evolve_all(&prune_out, &mut [&mut *client], &mut transcript, backend);
+    assert!(client.get_pending_members().is_empty());
+    assert!(transcript.get_pending_members().is_empty());
+    assert!(client.get_expired_members(Some(100)).is_empty());
+    assert_eq!(transcript.get_members(), client.get_members());
+
+    let error = client
+        .prune_expired_members(Some(100), backend)
+        .expect_err("Pruned without expired invitations");
+    assert!(matches!(error, EidError::InvalidMemberError(..)));
+}
+
+/// This test simulates the following:
+/// * create two EIDs with caller chosen ids and a transcript for the first one
+/// * create two EIDs with random ids
+/// * apply an evolvement of the second EID to the first one, expecting an Error
+///
+/// We compare the ids of clients and transcript
+#[apply(eid_backends)]
+fn eid_id<B: EidBackend>(backend: &B) {
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let eid_id = EidId::new("eid_1".into());
+    let client =
+        &mut B::ClientProvider::create_eid(Some(eid_id.clone()), &alice, alice_kp, backend)
+            .expect("Could not create EID");
+    let mut transcript = build_transcript(client, backend);
+    assert_eq!(eid_id, client.eid_id());
+    assert_eq!(eid_id, transcript.eid_id());
+
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let other_eid_id = EidId::new("eid_2".into());
+    let other_client =
+        &mut B::ClientProvider::create_eid(Some(other_eid_id.clone()), &bob, bob_kp, backend)
+            .expect("Could not create EID");
+    assert_eq!(other_eid_id, other_client.eid_id());
+
+    let random_client = B::ClientProvider::generate_initial_client("carol".into(), backend);
+    let other_random_client = B::ClientProvider::generate_initial_client("dave".into(), backend);
+    assert_ne!(random_client.eid_id(), other_random_client.eid_id());
+
+    // Both EIDs are in the same epoch, but the evolvement belongs to the other EID
+    let other_cross_sign_out = other_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let other_cross_sign_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&other_cross_sign_out);
+    assert_eq!(other_eid_id, other_cross_sign_in.eid_id());
+    assert_eq!(
+        EvolvementVerdict::WrongEid,
+        transcript.validate(&other_cross_sign_in, backend)
+    );
+    assert_eq!(
+        EvolvementVerdict::WrongEid,
+        client.validate(&other_cross_sign_in, backend)
+    );
+    let error = transcript
+        .evolve(other_cross_sign_in.clone(), backend)
+        .expect_err("Evolving with an evolvement of another EID");
+    assert!(matches!(error, EidError::WrongEidError(..)));
+    let error = client
+        .evolve(other_cross_sign_in, backend)
+        .expect_err("Evolving with an evolvement of another EID");
+    assert!(matches!(error, EidError::WrongEidError(..)));
+
+    // The EID still evolves with its own evolvements
+    cross_sign(client, &mut transcript, backend);
+    assert_eq!(1, client.get_members().len());
+    assert_eq!(eid_id, transcript.eid_id());
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript, add a second member
+/// * let both members prove their membership and verify the proofs
+/// * create a forged EID with the same id and epoch and let its member prove its membership
+/// * remove the second member
+///
+/// We verify the proofs against checkpoints of the transcript and against the trusted state of transcripts.
+/// The proof of the forged EID is rejected by both.
+#[apply(eid_backends)]
+fn membership_proof<B: EidBackend>(backend: &B) {
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    let error = alice
+        .prove_membership(backend)
+        .expect_err("Proving membership before cross-signing");
+    assert!(matches!(error, EidError::InactiveClientError(..)));
+    cross_sign(alice, &mut transcript, backend);
+
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let error = bob
+        .prove_membership(backend)
+        .expect_err("Proving membership before cross-signing");
+    assert!(matches!(error, EidError::InactiveClientError(..)));
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let eid_id = transcript.eid_id();
+    let epoch = transcript.epoch();
+    let alice_proof: <B::ClientProvider as EidClient>::MembershipProofProvider =
+        simulate_transfer(&alice.prove_membership(backend).expect("Proving failed"));
+    let bob_proof: <B::ClientProvider as EidClient>::MembershipProofProvider =
+        simulate_transfer(&bob.prove_membership(backend).expect("Proving failed"));
+    assert_eq!(eid_id, alice_proof.eid_id());
+    assert_eq!(epoch, alice_proof.epoch());
+
+    // Proofs can be verified by anyone who trusts a checkpoint of the transcript in the same epoch
+    let checkpoint = transcript
+        .checkpoint(backend)
+        .expect("Failed to create checkpoint");
+    let proven_alice = alice_proof
+        .verify(&checkpoint, backend)
+        .expect("Proof is invalid");
+    let proven_bob = bob_proof
+        .verify(&checkpoint, backend)
+        .expect("Proof is invalid");
+    assert_ne!(proven_alice, proven_bob);
+    assert!(transcript.get_members().contains(&proven_alice));
+    assert!(transcript.get_members().contains(&proven_bob));
+    let other_epoch = Checkpoint {
+        epoch: epoch + 1,
+        ..checkpoint.clone()
+    };
+    let error = alice_proof
+        .verify(&other_epoch, backend)
+        .expect_err("Verifying a proof for another epoch");
+    assert!(matches!(error, EidError::InvalidMembershipProofError(..)));
+    let other_eid = Checkpoint {
+        eid_id: EidId::new("other_eid".into()),
+        ..checkpoint.clone()
+    };
+    let error = alice_proof
+        .verify(&other_eid, backend)
+        .expect_err("Verifying a proof for another EID");
+    assert!(matches!(error, EidError::InvalidMembershipProofError(..)));
+
+    // Proofs can be checked against the trusted state of a transcript in the same epoch
+    let current_transcript = build_transcript(alice, backend);
+    let trusted_state = current_transcript
+        .get_trusted_state()
+        .expect("Failed to get trusted state");
+    assert_eq!(
+        proven_bob,
+        bob_proof
+            .verify_with_state(&trusted_state, backend)
+            .expect("Proof is invalid")
+    );
+    let initial_trusted_state = transcript
+        .get_trusted_state()
+        .expect("Failed to get trusted state");
+    assert!(alice_proof
+        .verify_with_state(&initial_trusted_state, backend)
+        .is_err());
+
+    // Anyone can create an EID with the same id and evolve it to the same epoch, but its proofs are rejected
+    let (mallory, mallory_kp) = B::ClientProvider::generate_member("mallory".into(), backend);
+    let mallory =
+        &mut B::ClientProvider::create_eid(Some(eid_id.clone()), &mallory, mallory_kp, backend)
+            .expect("failed to create EID");
+    let mut forged_transcript = build_transcript(mallory, backend);
+    cross_sign(mallory, &mut forged_transcript, backend);
+    while mallory.epoch() < epoch {
+        let update_out = mallory.update(backend).expect("Updating failed");
+        evolve_all(
+            &update_out,
+            &mut [&mut *mallory],
+            &mut forged_transcript,
+            backend,
+        );
+    }
+    let mallory_proof: <B::ClientProvider as EidClient>::MembershipProofProvider =
+        simulate_transfer(&mallory.prove_membership(backend).expect("Proving failed"));
+    assert_eq!(eid_id, mallory_proof.eid_id());
+    assert_eq!(epoch, mallory_proof.epoch());
+    let error = mallory_proof
+        .verify(&checkpoint, backend)
+        .expect_err("Verifying a proof of a forged EID");
+    assert!(matches!(error, EidError::InvalidMembershipProofError(..)));
+    let error = mallory_proof
+        .verify_with_state(&trusted_state, backend)
+        .expect_err("Verifying a proof of a forged EID");
+    assert!(matches!(error, EidError::InvalidMembershipProofError(..)));
+
+    // A removed member's proof is only valid for the epochs it was a member in
+    let remove_out = alice.remove(&proven_bob, backend).expect("Removing failed");
+    evolve_all(&remove_out, &mut [&mut *alice], &mut transcript, backend);
+    bob_proof
+        .verify(&checkpoint, backend)
+        .expect("Proof is invalid");
+    assert!(bob_proof
+        .verify(
+            &transcript
+                .checkpoint(backend)
+                .expect("Failed to create checkpoint"),
+            backend
+        )
+        .is_err());
+    let removed_transcript = build_transcript(alice, backend);
+    let trusted_state = removed_transcript
+        .get_trusted_state()
+        .expect("Failed to get trusted state");
+    assert!(bob_proof
+        .verify_with_state(&trusted_state, backend)
+        .is_err());
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript, add a second and a pending third member
+/// * let the members sign challenges and verify the responses
+/// * remove the second member and let it sign a challenge again
+///
+/// Only responses of current members for the challenged nonce are accepted.
+#[apply(eid_backends)]
+fn challenge_response<B: EidBackend>(backend: &B) {
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    cross_sign(alice, &mut transcript, backend);
+
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let (charlie_member, charlie_kp) =
+        B::ClientProvider::generate_member("charlie".into(), backend);
+    let add_out = alice
+        .add(&charlie_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(
+        &add_out,
+        &mut [&mut *alice, &mut *bob],
+        &mut transcript,
+        backend,
+    );
+    let charlie = B::ClientProvider::create_from_invitation(add_in, charlie_kp, backend)
+        .expect("failed to create client from invitation");
+
+    let nonce = b"nonce 1";
+    let bob_response: ChallengeResponse =
+        simulate_transfer(&bob.sign_challenge(nonce, backend).expect("Signing failed"));
+    let signer = transcript
+        .verify_challenge(nonce, &bob_response, backend)
+        .expect("Response is invalid");
+    assert!(transcript.get_members().contains(&signer));
+    assert_ne!(
+        signer,
+        transcript
+            .verify_challenge(
+                nonce,
+                &alice
+                    .sign_challenge(nonce, backend)
+                    .expect("Signing failed"),
+                backend
+            )
+            .expect("Response is invalid")
+    );
+
+    // The response only answers the challenged nonce
+    let error = transcript
+        .verify_challenge(b"nonce 2", &bob_response, backend)
+        .expect_err("Verifying a response to another nonce");
+    assert!(matches!(error, EidError::InvalidChallengeResponseError(..)));
+    let mut tampered_response = bob_response.clone();
+    tampered_response.signature[0] ^= 1;
+    let error = transcript
+        .verify_challenge(nonce, &tampered_response, backend)
+        .expect_err("Verifying a tampered response");
+    assert!(matches!(error, EidError::InvalidChallengeResponseError(..)));
+
+    // Pending members haven't cross-signed their membership yet
+    let charlie_response = charlie
+        .sign_challenge(nonce, backend)
+        .expect("Signing failed");
+    let error = transcript
+        .verify_challenge(nonce, &charlie_response, backend)
+        .expect_err("Verifying a response of a pending member");
+    assert!(matches!(error, EidError::InvalidChallengeResponseError(..)));
+
+    // A removed member can still sign, but its responses are rejected
+    let remove_out = alice.remove(&signer, backend).expect("Removing failed");
+    evolve_all(&remove_out, &mut [&mut *alice], &mut transcript, backend);
+    let nonce = b"nonce 3";
+    let bob_response = bob.sign_challenge(nonce, backend).expect("Signing failed");
+    let error = transcript
+        .verify_challenge(nonce, &bob_response, backend)
+        .expect_err("Verifying a response of a removed member");
+    assert!(matches!(error, EidError::InvalidChallengeResponseError(..)));
+    assert!(!transcript.get_members().contains(&signer));
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript, and a transcript of a forged EID with the same id and epoch
+/// * add a second member
+/// * let both members update concurrently, only the first update is applied
+/// * rebuild the transcript from its trusted state and log
+/// * tamper with the log by replacing, dropping and reordering entries
+///
+/// We verify the log and compare the heads of the hash chain.
+#[apply(eid_backends)]
+fn log_hash_chain<B: EidBackend>(backend: &B) {
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    let initial_head = transcript.head_hash();
+    transcript.verify_log(backend).expect("Log is invalid");
+
+    // The first head commits to the members of the trusted state, not just to the EID's id and epoch
+    let (mallory, mallory_kp) = B::ClientProvider::generate_member("mallory".into(), backend);
+    let mallory =
+        B::ClientProvider::create_eid(Some(transcript.eid_id()), &mallory, mallory_kp, backend)
+            .expect("failed to create EID");
+    let forged_transcript = build_transcript(&mallory, backend);
+    assert_eq!(transcript.epoch(), forged_transcript.epoch());
+    assert_ne!(initial_head, forged_transcript.head_hash());
+    cross_sign(alice, &mut transcript, backend);
+    assert_ne!(initial_head, transcript.head_hash());
+
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    // Bob's update competes with Alice's update and is never applied
+    let bob_update_out = bob.update(backend).expect("Updating failed");
+    let bob_update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&bob_update_out);
+    let alice_update_out = alice.update(backend).expect("Updating failed");
+    evolve_all(
+        &alice_update_out,
+        &mut [&mut *alice],
+        &mut transcript,
+        backend,
+    );
+    transcript.verify_log(backend).expect("Log is invalid");
+
+    let log = transcript.log();
+    let rebuild = |log: Vec<<B::ClientProvider as EidClient>::EvolvementProvider>| {
+        let trusted_state = transcript
+            .get_trusted_state()
+            .expect("Failed to get trusted state");
+        <B::ClientProvider as EidClient>::TranscriptProvider::new(trusted_state, log, backend)
+            .expect("Failed to create transcript")
+    };
+    let rebuilt = rebuild(log.clone());
+    rebuilt.verify_log(backend).expect("Log is invalid");
+    assert_eq!(transcript.head_hash(), rebuilt.head_hash());
+
+    // A log with the competing update is valid, but leads to a different head
+    let mut forked_log = log.clone();
+    *forked_log.last_mut().unwrap() = bob_update_in.clone();
+    let forked = rebuild(forked_log);
+    forked.verify_log(backend).expect("Log is invalid");
+    assert_ne!(transcript.head_hash(), forked.head_hash());
+
+    // Changing any stored entry is detected
+    for index in 0..log.len() {
+        let mut tampered = rebuild(log.clone());
+        tampered.log_mut()[index] = log[(index + 1) % log.len()].clone();
+        let error = tampered
+            .verify_log(backend)
+            .expect_err("Verifying a tampered log");
+        assert!(matches!(error, EidError::InvalidLogError(..)));
+    }
+    let mut tampered = rebuild(log.clone());
+    *tampered.log_mut().last_mut().unwrap() = bob_update_in;
+    let error = tampered
+        .verify_log(backend)
+        .expect_err("Verifying a log with a replaced entry");
+    assert!(matches!(error, EidError::InvalidLogError(..)));
+
+    // Dropping and reordering entries is detected
+    let mut tampered = rebuild(log.clone());
+    tampered.log_mut().pop();
+    let error = tampered
+        .verify_log(backend)
+        .expect_err("Verifying a log with a dropped entry");
+    assert!(matches!(error, EidError::InvalidLogError(..)));
+    let mut tampered = rebuild(log);
+    tampered.log_mut().swap(1, 2);
+    let error = tampered
+        .verify_log(backend)
+        .expect_err("Verifying a reordered log");
+    assert!(matches!(error, EidError::InvalidLogError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript, add a second member and let both members update
+/// * remember the root of the Merkle tree over the log after each evolvement
+/// * prove the inclusion of each evolvement and the consistency of each earlier log with the current log
+///
+/// We verify the proofs with the remembered roots only.
+#[apply(eid_backends)]
+fn merkle_proofs<B: EidBackend>(backend: &B) {
+    let current_root = |transcript: &<B::ClientProvider as EidClient>::TranscriptProvider| {
+        transcript
+            .log_root(transcript.log().len() as u64, backend)
+            .expect("Failed to get the root of the log")
+    };
+
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    let mut roots = vec![current_root(&transcript)];
+    cross_sign(alice, &mut transcript, backend);
+    roots.push(current_root(&transcript));
+
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    roots.push(current_root(&transcript));
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    roots.push(current_root(&transcript));
+    for _ in 0..2 {
+        let update_out = alice.update(backend).expect("Updating failed");
+        evolve_all(
+            &update_out,
+            &mut [&mut *alice, &mut *bob],
+            &mut transcript,
+            backend,
+        );
+        roots.push(current_root(&transcript));
+        let update_out = bob.update(backend).expect("Updating failed");
+        evolve_all(
+            &update_out,
+            &mut [&mut *alice, &mut *bob],
+            &mut transcript,
+            backend,
+        );
+        roots.push(current_root(&transcript));
+    }
+    let log = transcript.log();
+    let size = log.len();
+    assert_eq!(size + 1, roots.len());
+    assert!(transcript.log_root(size as u64 + 1, backend).is_err());
+    let root = &roots[size];
+
+    for (index, evolvement) in log.iter().enumerate() {
+        let proof: InclusionProof = simulate_transfer(
+            &transcript
+                .prove_inclusion(evolvement, backend)
+                .expect("Proving inclusion failed"),
+        );
+        assert_eq!(index as u64, proof.leaf_index);
+        let leaf_hash =
+            <B::ClientProvider as EidClient>::TranscriptProvider::leaf_hash(evolvement, backend)
+                .expect("No leaf hash");
+        proof
+            .verify(backend, &leaf_hash, root)
+            .expect("Inclusion proof is invalid");
+        let error = proof
+            .verify(backend, &leaf_hash, &roots[size - 1])
+            .expect_err("Verifying an inclusion proof with another root");
+        assert!(matches!(error, EidError::InvalidMerkleProofError(..)));
+        let other_leaf_hash = <B::ClientProvider as EidClient>::TranscriptProvider::leaf_hash(
+            &log[(index + 1) % size],
+            backend,
+        )
+        .expect("No leaf hash");
+        assert!(proof.verify(backend, &other_leaf_hash, root).is_err());
+    }
+
+    for first_size in 1..=size {
+        let proof: ConsistencyProof = simulate_transfer(
+            &transcript
+                .prove_consistency(first_size as u64, backend)
+                .expect("Proving consistency failed"),
+        );
+        proof
+            .verify(backend, &roots[first_size], root)
+            .expect("Consistency proof is invalid");
+        let error = proof
+            .verify(backend, &roots[first_size - 1], root)
+            .expect_err("Verifying a consistency proof with another root");
+        assert!(matches!(error, EidError::InvalidMerkleProofError(..)));
+    }
+    assert!(transcript.prove_consistency(0, backend).is_err());
+    assert!(transcript
+        .prove_consistency(size as u64 + 1, backend)
+        .is_err());
+
+    // An evolvement that isn't part of the log
+    let update_out = alice.update(backend).expect("Updating failed");
+    let update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&update_out);
+    let error = transcript
+        .prove_inclusion(&update_in, backend)
+        .expect_err("Proving inclusion of an evolvement that isn't part of the log");
+    assert!(matches!(error, EidError::InvalidMerkleProofError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript and add a second member
+/// * let the transcript operator sign checkpoints of the transcript
+/// * let the operator show a forked log in which a competing update was applied
+///
+/// We verify that signed checkpoints match the view of honest transcripts
+/// and that checkpoints of the fork are detected as a split view.
+#[apply(eid_backends)]
+fn checkpoints<B: EidBackend>(backend: &B) {
+    let operator_key = backend
+        .generate_signing_key()
+        .expect("Failed to generate operator key");
+    let operator_public_key = backend.public_key(&operator_key);
+    let other_key = backend
+        .generate_signing_key()
+        .expect("Failed to generate operator key");
+
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    cross_sign(alice, &mut transcript, backend);
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+
+    let earlier: SignedCheckpoint = simulate_transfer(
+        &transcript
+            .sign_checkpoint(&operator_key, backend)
+            .expect("Signing the checkpoint failed"),
+    );
+    let checkpoint = earlier
+        .verify(&operator_public_key, backend)
+        .expect("Checkpoint is invalid");
+    assert_eq!(transcript.eid_id(), checkpoint.eid_id);
+    assert_eq!(transcript.epoch(), checkpoint.epoch);
+    assert_eq!(transcript.log().len() as u64, checkpoint.log_size);
+    assert_eq!(transcript.head_hash(), checkpoint.head_hash);
+    assert_eq!(
+        transcript
+            .members_digest(backend)
+            .expect("No members digest"),
+        checkpoint.members_digest
+    );
+    transcript
+        .verify_checkpoint(&earlier, &operator_public_key, backend)
+        .expect("Checkpoint contradicts the transcript");
+
+    // Checkpoints that weren't signed by the operator are rejected
+    let forged = transcript
+        .sign_checkpoint(&other_key, backend)
+        .expect("Signing the checkpoint failed");
+    let error = transcript
+        .verify_checkpoint(&forged, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint of another operator");
+    assert!(matches!(error, EidError::InvalidCheckpointError(..)));
+    let mut tampered = earlier.clone();
+    tampered.checkpoint.epoch += 1;
+    let error = tampered
+        .verify(&operator_public_key, backend)
+        .expect_err("Verifying a tampered checkpoint");
+    assert!(matches!(error, EidError::InvalidCheckpointError(..)));
+
+    // Bob's update competes with Alice's update, which the honest transcript applies
+    let bob_update_out = bob.update(backend).expect("Updating failed");
+    let bob_update_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&bob_update_out);
+    let alice_update_out = alice.update(backend).expect("Updating failed");
+    evolve_all(
+        &alice_update_out,
+        &mut [&mut *alice],
+        &mut transcript,
+        backend,
+    );
+    let current = transcript
+        .sign_checkpoint(&operator_key, backend)
+        .expect("Signing the checkpoint failed");
+
+    let rebuild = |log: Vec<<B::ClientProvider as EidClient>::EvolvementProvider>| {
+        let trusted_state = transcript
+            .get_trusted_state()
+            .expect("Failed to get trusted state");
+        <B::ClientProvider as EidClient>::TranscriptProvider::new(trusted_state, log, backend)
+            .expect("Failed to create transcript")
+    };
+    let log = transcript.log();
+    let auditor = rebuild(log.clone());
+    for signed_checkpoint in [&earlier, &current] {
+        auditor
+            .verify_checkpoint(signed_checkpoint, &operator_public_key, backend)
+            .expect("Checkpoint contradicts the transcript");
+    }
+
+    // The operator shows a fork with Bob's update to other clients
+    let mut forked_log = log.clone();
+    *forked_log.last_mut().unwrap() = bob_update_in;
+    let fork = rebuild(forked_log);
+    let forked = fork
+        .sign_checkpoint(&operator_key, backend)
+        .expect("Signing the checkpoint failed");
+    assert_eq!(current.checkpoint.log_size, forked.checkpoint.log_size);
+    fork.verify_checkpoint(&earlier, &operator_public_key, backend)
+        .expect("Checkpoint contradicts the fork");
+    let error = auditor
+        .verify_checkpoint(&forked, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint of a fork");
+    assert!(matches!(error, EidError::SplitViewError(..)));
+    let error = fork
+        .verify_checkpoint(&current, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint of another fork");
+    assert!(matches!(error, EidError::SplitViewError(..)));
+
+    // A transcript that is ahead of the checkpoint still detects the fork
+    let update_out = alice.update(backend).expect("Updating failed");
+    evolve_all(&update_out, &mut [&mut *alice], &mut transcript, backend);
+    transcript
+        .verify_checkpoint(&current, &operator_public_key, backend)
+        .expect("Checkpoint contradicts the transcript");
+    let error = transcript
+        .verify_checkpoint(&forked, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint of a fork");
+    assert!(matches!(error, EidError::SplitViewError(..)));
+
+    // A transcript that lags behind can't compare the checkpoint yet
+    let lagging = rebuild(log[..earlier.checkpoint.log_size as usize].to_vec());
+    let error = lagging
+        .verify_checkpoint(&forked, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint of a longer log");
+    assert!(matches!(error, EidError::IncomparableCheckpointError(..)));
+
+    // Checkpoints of other EIDs are rejected
+    let carol = &mut B::ClientProvider::generate_initial_client("carol".into(), backend);
+    let other_transcript = build_transcript(carol, backend);
+    let error = other_transcript
+        .verify_checkpoint(&earlier, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint of another EID");
+    assert!(matches!(error, EidError::WrongEidError(..)));
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript, add a second member and let both members update
+/// * compact the transcript to the epoch in which the second member joined
+/// * keep evolving the compacted transcript
+///
+/// We verify that the compacted transcript keeps its members and hash chain,
+/// and that it can be rebuilt from its new trusted state and pruned prefix with the same head and log size.
+/// Checkpoints whose head was pruned can no longer be compared,
+/// and Merkle roots and consistency proofs are only available for logs that end after the pruned prefix.
+#[apply(eid_backends)]
+fn compaction<B: EidBackend>(backend: &B) {
+    let operator_key = backend
+        .generate_signing_key()
+        .expect("Failed to generate operator key");
+    let operator_public_key = backend.public_key(&operator_key);
+
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    let initial_epoch = transcript.epoch();
+    cross_sign(alice, &mut transcript, backend);
+    let pruned = transcript
+        .sign_checkpoint(&operator_key, backend)
+        .expect("Signing the checkpoint failed");
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    let compact_epoch = transcript.epoch();
+    let compact_size = transcript.log().len();
+    for _ in 0..2 {
+        let update_out = alice.update(backend).expect("Updating failed");
+        evolve_all(
+            &update_out,
+            &mut [&mut *alice, &mut *bob],
+            &mut transcript,
+            backend,
+        );
+    }
+    let earlier = transcript
+        .sign_checkpoint(&operator_key, backend)
+        .expect("Signing the checkpoint failed");
+    let log = transcript.log();
+    let log_size = transcript.log_size();
+    let head_hash = transcript.head_hash();
+    let members = transcript.get_members();
+    assert!(transcript.log_prefix().is_none());
+
+    // Compacting to the current trusted state prunes nothing
+    let archived = transcript
+        .compact(initial_epoch, backend)
+        .expect("Compacting failed");
+    assert!(archived.is_empty());
+    assert!(transcript.log_prefix().is_none());
+
+    let archived = transcript
+        .compact(compact_epoch, backend)
+        .expect("Compacting failed");
+    assert!(log[..compact_size] == archived[..]);
+    assert!(log[compact_size..] == transcript.log()[..]);
+    let prefix = transcript.log_prefix().expect("No log prefix");
+    assert_eq!(compact_size as u64, prefix.size);
+    assert_eq!(
+        Some(prefix.head_hash.clone()),
+        transcript.head_hash_at(prefix.size)
+    );
+    assert!(transcript.head_hash_at(prefix.size - 1).is_none());
+    assert_eq!(
+        compact_epoch,
+        transcript
+            .get_trusted_state()
+            .expect("Failed to get trusted state")
+            .epoch()
+    );
+    assert_eq!(log_size, transcript.log_size());
+    assert_eq!(head_hash, transcript.head_hash());
+    assert_eq!(members, transcript.get_members());
+    transcript.verify_log(backend).expect("Log is invalid");
+    transcript
+        .verify_checkpoint(&earlier, &operator_public_key, backend)
+        .expect("Checkpoint contradicts the transcript");
+    let error = transcript
+        .verify_checkpoint(&pruned, &operator_public_key, backend)
+        .expect_err("Verifying a checkpoint whose head was pruned");
+    assert!(matches!(error, EidError::IncomparableCheckpointError(..)));
+
+    // Epochs before the trusted state or after the current state can't be compacted to
+    for epoch in [initial_epoch, transcript.epoch() + 1] {
+        let error = transcript
+            .compact(epoch, backend)
+            .expect_err("Compacting to an epoch without state");
+        assert!(matches!(error, EidError::CompactTranscriptError(..)));
+    }
+
+    // The compacted transcript keeps evolving
+    let update_out = bob.update(backend).expect("Updating failed");
+    evolve_all(
+        &update_out,
+        &mut [&mut *alice, &mut *bob],
+        &mut transcript,
+        backend,
+    );
+    transcript.verify_log(backend).expect("Log is invalid");
+    assert_eq!(log_size + 1, transcript.log_size());
+    transcript
+        .verify_checkpoint(&earlier, &operator_public_key, backend)
+        .expect("Checkpoint contradicts the transcript");
+    let remaining = transcript.log();
+    let proof = transcript
+        .prove_inclusion(remaining.last().unwrap(), backend)
+        .expect("Proving inclusion failed");
+    let leaf_hash = <B::ClientProvider as EidClient>::TranscriptProvider::leaf_hash(
+        remaining.last().unwrap(),
+        backend,
+    )
+    .expect("No leaf hash");
+    proof
+        .verify(
+            backend,
+            &leaf_hash,
+            &transcript
+                .log_root(transcript.log_size(), backend)
+                .expect("Failed to get the root of the log"),
+        )
+        .expect("Inclusion proof is invalid");
+    for size in [prefix.size - 1, prefix.size] {
+        let error = transcript
+            .log_root(size, backend)
+            .expect_err("Getting the root of a pruned log");
+        assert!(matches!(error, EidError::InvalidMerkleProofError(..)));
+        let error = transcript
+            .prove_consistency(size, backend)
+            .expect_err("Proving consistency with a pruned log");
+        assert!(matches!(error, EidError::InvalidMerkleProofError(..)));
+    }
+    let proof = transcript
+        .prove_consistency(prefix.size + 1, backend)
+        .expect("Proving consistency failed");
+    proof
+        .verify(
+            backend,
+            &transcript
+                .log_root(prefix.size + 1, backend)
+                .expect("Failed to get the root of the log"),
+            &transcript
+                .log_root(transcript.log_size(), backend)
+                .expect("Failed to get the root of the log"),
+        )
+        .expect("Consistency proof is invalid");
+
+    // The transcript can be rebuilt from the new trusted state, the pruned prefix and the remaining log
+    let trusted_state = transcript
+        .get_trusted_state()
+        .expect("Failed to get trusted state");
+    let rebuilt = <B::ClientProvider as EidClient>::TranscriptProvider::new_with_prefix(
+        trusted_state,
+        transcript.log_prefix(),
+        remaining,
+        backend,
+    )
+    .expect("Failed to create transcript");
+    assert_eq!(transcript.epoch(), rebuilt.epoch());
+    assert_eq!(transcript.get_members(), rebuilt.get_members());
+    assert_eq!(transcript.head_hash(), rebuilt.head_hash());
+    assert_eq!(transcript.log_size(), rebuilt.log_size());
+    assert_eq!(transcript.log_prefix(), rebuilt.log_prefix());
+    rebuilt.verify_log(backend).expect("Log is invalid");
+    rebuilt
+        .verify_checkpoint(&earlier, &operator_public_key, backend)
+        .expect("Checkpoint contradicts the transcript");
+}
+
+/// This test simulates the following:
+/// * create a new client and a transcript
+/// * add a second member that cross-signs its membership, updates its keys and is removed again
+///
+/// We verify the members of the transcript at each epoch and the history of the second member.
+#[apply(eid_backends)]
+fn historical_membership<B: EidBackend>(backend: &B) {
+    let alice = &mut B::ClientProvider::generate_initial_client("alice".into(), backend);
+    let mut transcript = build_transcript(alice, backend);
+    let initial_epoch = transcript.epoch();
+    let initial_members = transcript.get_members();
+    cross_sign(alice, &mut transcript, backend);
+    let alice_epoch = transcript.epoch();
+    let alice_members = transcript.get_members();
+
+    let (bob_member, bob_kp) = B::ClientProvider::generate_member("bob".into(), backend);
+    let add_out = alice
+        .add(&bob_member, backend)
+        .expect("failed to add member");
+    let add_in = evolve_all(&add_out, &mut [&mut *alice], &mut transcript, backend);
+    let add_epoch = transcript.epoch();
+    let bob = &mut B::ClientProvider::create_from_invitation(add_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_in = cross_sign(bob, &mut transcript, backend);
+    alice
+        .evolve(bob_cross_sign_in, backend)
+        .expect("Failed to evolve");
+    let cross_sign_epoch = transcript.epoch();
+    let both_members = transcript.get_members();
+    assert_eq!(2, both_members.len());
+
+    let update_out = bob.update(backend).expect("Updating failed");
+    evolve_all(
+        &update_out,
+        &mut [&mut *alice, &mut *bob],
+        &mut transcript,
+        backend,
+    );
+    let update_epoch = transcript.epoch();
+    let remove_out = alice
+        .remove(&bob_member, backend)
+        .expect("failed to remove member");
+    evolve_all(&remove_out, &mut [&mut *alice], &mut transcript, backend);
+    let remove_epoch = transcript.epoch();
+
+    for (epoch, members) in [
+        (initial_epoch, &initial_members),
+        (alice_epoch, &alice_members),
+        (cross_sign_epoch, &both_members),
+        (remove_epoch, &alice_members),
+    ] {
+        assert_eq!(
+            members,
+            &transcript
+                .get_members_at(epoch, backend)
+                .expect("Failed to get members")
+        );
+    }
+    let error = transcript
+        .get_members_at(remove_epoch + 1, backend)
+        .expect_err("Getting members of a future epoch");
+    assert!(matches!(error, EidError::UnknownEpochError(..)));
+
+    // Bob was a member at the time of his update, but not after his removal
+    assert!(transcript
+        .get_members_at(update_epoch, backend)
+        .expect("Failed to get members")
+        .contains(&bob_member));
+    assert!(!transcript
+        .get_members_at(remove_epoch, backend)
+        .expect("Failed to get members")
+        .contains(&bob_member));
+
+    let history = transcript
+        .member_history(&bob_member, backend)
+        .expect("Failed to get member history");
+    let epochs: Vec<_> = history.iter().map(|record| record.epoch).collect();
+    assert_eq!(
+        vec![add_epoch, cross_sign_epoch, update_epoch, remove_epoch],
+        epochs
+    );
+    assert!(matches!(history[0].event, MembershipEvent::MemberAdded(..)));
+    assert!(matches!(
+        history[1].event,
+        MembershipEvent::MemberCrossSigned(..)
+    ));
+    assert!(matches!(
+        history[2].event,
+        MembershipEvent::MemberUpdatedKey(..)
+    ));
+    assert!(matches!(
+        history[3].event,
+        MembershipEvent::MemberRemoved(..)
+    ));
+    assert!(history
+        .iter()
+        .all(|record| record.event.member() == &bob_member));
+
+    // The history only reaches back to the trusted state
+    transcript
+        .compact(cross_sign_epoch, backend)
+        .expect("Compacting failed");
+    let error = transcript
+        .get_members_at(alice_epoch, backend)
+        .expect_err("Getting members of a pruned epoch");
+    assert!(matches!(error, EidError::UnknownEpochError(..)));
+    let history = transcript
+        .member_history(&bob_member, backend)
+        .expect("Failed to get member history");
+    let epochs: Vec<_> = history.iter().map(|record| record.epoch).collect();
+    assert_eq!(vec![update_epoch, remove_epoch], epochs);
+}
+
+/// This test simulates the following:
+/// * create a new client
+/// * create a transcript from the client's state
+/// * let the client cross sign its membership and add a second member
+/// * export the client's state and restore it with a fresh backend
+/// * let the second member and the restored client update their key material
+/// * let the restored client add a third member
+///
+/// The original client is not used after the export, the second member checks the evolvements of the restored client.
+/// We evolve the restored client, the second member and the transcript along the way, comparing their states
+#[apply(eid_backends)]
+fn restore<B: EidBackend>(backend: &B) {
+    let client = &mut B::ClientProvider::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = B::ClientProvider::generate_member("alice".into(), backend);
+    let add_alice_evolvement_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_evolvement_in = evolve_all(
+        &add_alice_evolvement_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client =
+        &mut B::ClientProvider::create_from_invitation(add_alice_evolvement_in, alice_kp, backend)
+            .expect("failed to create client from invitation");
+    let cross_sign_alice_evolvement_in = cross_sign(alice_client, &mut transcript, backend);
+    client
+        .evolve(cross_sign_alice_evolvement_in, backend)
+        .expect("Failed to evolve");
+
+    let exported_state = client
+        .export_client_state(backend)
+        .expect("Failed to export client state");
+    let imported_state: <B::ClientProvider as EidClient>::ExportedClientStateProvider =
+        simulate_transfer(&exported_state);
+
+    // Simulate a restart of the device by restoring into a fresh backend
+    let restored_backend = &backend.fresh();
+    let restored_client =
+        &mut B::ClientProvider::restore_client_state(imported_state, restored_backend)
+            .expect("Failed to restore client state");
+
+    assert_eq!(client.eid_id(), restored_client.eid_id());
+    assert_eq!(client.epoch(), restored_client.epoch());
+    assert_eq!(restored_client.get_members(), client.get_members());
+    assert_eq!(transcript.get_members(), restored_client.get_members());
+    // The restored client holds the same key material as the original one
+    let reexported_state = restored_client
+        .export_client_state(restored_backend)
+        .expect("Failed to export restored client state");
+    assert_eq!(
+        exported_state
+            .tls_serialize_detached()
+            .expect("Failed to serialize"),
+        reexported_state
+            .tls_serialize_detached()
+            .expect("Failed to serialize")
+    );
+
+    // Applying Alice's update requires the private keys of the restored client's leaf
+    let alice_update_evolvement_out = alice_client
+        .update(backend)
+        .expect("Updating client keys failed");
+    let alice_update_evolvement_in = evolve_all(
+        &alice_update_evolvement_out,
+        &mut [&mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    restored_client
+        .evolve(alice_update_evolvement_in, restored_backend)
+        .expect("Failed to apply update on restored client state");
+    assert_eq!(alice_client.epoch(), restored_client.epoch());
+
+    let update_evolvement_out = restored_client
+        .update(restored_backend)
+        .expect("Updating client keys failed");
+    let update_evolvement_in: <B::ClientProvider as EidClient>::EvolvementProvider =
+        simulate_transfer(&update_evolvement_out);
+    restored_client
+        .evolve(update_evolvement_in.clone(), restored_backend)
+        .expect("Failed to apply update on restored client state");
+    transcript
+        .evolve(update_evolvement_in.clone(), backend)
+        .expect("Failed to evolve transcript");
+    alice_client
+        .evolve(update_evolvement_in, backend)
+        .expect("Failed to apply update of restored client");
+
+    assert_eq!(alice_client.epoch(), restored_client.epoch());
+    assert_eq!(transcript.get_members(), restored_client.get_members());
+    assert_eq!(alice_client.get_members(), restored_client.get_members());
+
+    let (bob, bob_kp) = B::ClientProvider::generate_member("bob".into(), restored_backend);
+    let add_bob_evolvement_out = restored_client
+        .add(&bob, restored_backend)
+        .expect("failed to add member");
+    let add_bob_evolvement_in = evolve_all(
+        &add_bob_evolvement_out,
+        &mut [&mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    restored_client
+        .evolve(add_bob_evolvement_in.clone(), restored_backend)
+        .expect("Failed to evolve");
+
+    let bob_client = &mut B::ClientProvider::create_from_invitation(
+        add_bob_evolvement_in,
+        bob_kp,
+        restored_backend,
+    )
+    .expect("failed to create client from invitation");
+    let cross_sign_evolvement_in = cross_sign(bob_client, &mut transcript, restored_backend);
+    restored_client
+        .evolve(cross_sign_evolvement_in.clone(), restored_backend)
+        .expect("Failed to evolve");
+    alice_client
+        .evolve(cross_sign_evolvement_in, backend)
+        .expect("Failed to evolve");
+
+    let members = restored_client.get_members();
+    assert!(members.contains(&bob));
+    assert_eq!(3, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(alice_client.get_members(), members);
+    assert_eq!(alice_client.epoch(), restored_client.epoch());
+}
+
+/// A temporary directory that is removed when it is dropped, even if the test fails.
+struct TempDir(std::path::PathBuf);
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// This test is specific to the MLS implementation and simulates the following:
+/// * create a new client and a transcript
+/// * create a member in a backend that uses file storage
+/// * add the member to the EID
+/// * restart the member's backend by creating a new one on the same directory
+/// * read the member's signature key from the storage
+/// * let the member join from the invitation, which requires the key package's private key
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[test]
+fn mls_file_storage() {
+    let directory = TempDir(
+        std::env::temp_dir().join(format!("eid_mls_file_storage_{}", rand::random::<u64>())),
+    );
+    let backend = &EidMlsBackend::default();
+    let client = &mut EidMlsClient::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    // Only the public signature key is kept in memory across the restart
+    let (bob, bob_signature_key) = {
+        let bob_backend = &EidMlsBackend::with_storage(
+            EidMlsFileStorage::new(directory.0.clone()).expect("Failed to create file storage"),
+        )
+        .expect("Failed to create backend");
+        let (bob, bob_kp) = EidMlsClient::generate_member("bob".into(), bob_backend);
+        (bob, bob_kp.to_public_vec())
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = |path: &std::path::Path| {
+            std::fs::metadata(path)
+                .expect("Failed to read metadata")
+                .permissions()
+                .mode()
+                & 0o777
+        };
+        assert_eq!(0o700, mode(&directory.0));
+        for entry in std::fs::read_dir(&directory.0).expect("Failed to read directory") {
+            let path = entry.expect("Failed to read directory entry").path();
+            assert_eq!(0o600, mode(&path));
+        }
+    }
+
+    let add_bob_evolvement_out = client.add(&bob, backend).expect("failed to add member");
+    let add_bob_evolvement_in: EidMlsEvolvement = simulate_transfer(&add_bob_evolvement_out);
+    transcript
+        .evolve(add_bob_evolvement_in.clone(), backend)
+        .expect("Failed to add evolvement to transcript");
+    client
+        .evolve(add_bob_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+
+    let bob_backend = &EidMlsBackend::with_storage(
+        EidMlsFileStorage::new(directory.0.clone()).expect("Failed to create file storage"),
+    )
+    .expect("Failed to create backend");
+    let bob_kp = EidMlsClient::read_stored_key_pair(&bob_signature_key, bob_backend)
+        .expect("Failed to read signature key from storage");
+    let bob_client =
+        &mut EidMlsClient::create_from_invitation(add_bob_evolvement_in, bob_kp, bob_backend)
+            .expect("failed to create client from invitation");
+
+    let cross_sign_evolvement_in = cross_sign(bob_client, &mut transcript, bob_backend);
+    client
+        .evolve(cross_sign_evolvement_in, backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(members.contains(&bob));
+    assert_eq!(2, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(bob_client.get_members(), members);
+}
+
+/// This test is specific to the MLS implementation:
+/// it runs the tests of [eid_backends] for every ciphersuite the crypto provider supports.
+/// The default ciphersuite is already covered by the `EidMls` case.
+#[test]
+fn mls_supported_ciphersuites() {
+    for ciphersuite in EidMlsBackend::supported_ciphersuites() {
+        if ciphersuite == DEFAULT_CIPHERSUITE {
+            continue;
+        }
+        let backend = &EidMlsBackend::with_ciphersuite(ciphersuite)
+            .expect("Creating a backend with a supported ciphersuite");
+        add(backend);
+        remove(backend);
+        leave(backend);
+        update(backend);
+        add_remove_many(backend);
+        replace(backend);
+        proposals(backend);
+        epochs(backend);
+        buffered_evolvements(backend);
+        conflicts(backend);
+        atomic_batch_evolve(backend);
+        validate(backend);
+        describe(backend);
+        membership_events(backend);
+        pending_members(backend);
+        invitation_expiry(backend);
+        eid_id(backend);
+        membership_proof(backend);
+        challenge_response(backend);
+        log_hash_chain(backend);
+        merkle_proofs(backend);
+        checkpoints(backend);
+        compaction(backend);
+        historical_membership(backend);
+        restore(backend);
+    }
+}
+
+/// This test is specific to the MLS implementation:
+/// creating a backend with a ciphersuite that the crypto provider doesn't support fails.
+#[test]
+fn mls_unsupported_ciphersuite() {
+    let error =
+        EidMlsBackend::with_ciphersuite(Ciphersuite::MLS_256_DHKEMX448_AES256GCM_SHA512_Ed448)
+            .err()
+            .expect("Creating a backend with an unsupported ciphersuite");
+    assert!(matches!(error, EidError::UnsupportedCiphersuiteError(..)));
+
+    for ciphersuite in EidMlsBackend::supported_ciphersuites() {
+        let backend = EidMlsBackend::with_ciphersuite(ciphersuite)
+            .expect("Creating a backend with a supported ciphersuite");
+        assert_eq!(ciphersuite, backend.ciphersuite());
+    }
+}
+
+/// This test is specific to the MLS implementation and simulates the following:
+/// * create a new client and a transcript, add Alice
+/// * let Alice update her key material before cross signing, which doesn't make her a member
+/// * let Alice cross sign her membership
+/// * add Bob, who learns from the invitation which members have cross signed
+/// * create a transcript from Bob's state
+///
+/// We compare the members of clients and transcripts along the way
+#[test]
+fn mls_cross_sign() {
+    let backend = &EidMlsBackend::default();
+    let client = &mut EidMlsClient::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = EidMlsClient::generate_member("alice".into(), backend);
+    let add_alice_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_in = evolve_all(
+        &add_alice_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client = &mut EidMlsClient::create_from_invitation(add_alice_in, alice_kp, backend)
+        .expect("failed to create client from invitation");
+
+    // An update doesn't carry a cross signature
+    let alice_update_out = alice_client
+        .update(backend)
+        .expect("Updating client keys failed");
+    let alice_update_in: EidMlsEvolvement = simulate_transfer(&alice_update_out);
+    let summary = transcript
+        .describe(&alice_update_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(EvolvementKind::Update, summary.kind);
+    evolve_all(
+        &alice_update_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    assert_eq!(vec![alice.clone()], transcript.get_pending_members());
+    assert_eq!(vec![alice.clone()], client.get_pending_members());
+    assert_eq!(vec![alice.clone()], alice_client.get_pending_members());
+
+    let alice_cross_sign_out = alice_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    let alice_cross_sign_in: EidMlsEvolvement = simulate_transfer(&alice_cross_sign_out);
+    assert_eq!(
+        EvolvementVerdict::Valid,
+        transcript.validate(&alice_cross_sign_in, backend)
+    );
+    let summary = transcript
+        .describe(&alice_cross_sign_in, backend)
+        .expect("Failed to describe");
+    assert_eq!(EvolvementKind::CrossSign, summary.kind);
+    evolve_all(
+        &alice_cross_sign_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    assert!(transcript.get_pending_members().is_empty());
+    assert!(client.get_members().contains(&alice));
+    assert_eq!(transcript.get_members(), client.get_members());
+    assert_eq!(alice_client.get_members(), client.get_members());
+
+    // Bob learns which members have cross signed from the invitation
+    let (bob, bob_kp) = EidMlsClient::generate_member("bob".into(), backend);
+    let add_bob_out = client.add(&bob, backend).expect("failed to add member");
+    let add_bob_in = evolve_all(
+        &add_bob_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    let bob_client = &mut EidMlsClient::create_from_invitation(add_bob_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    assert_eq!(client.get_members(), bob_client.get_members());
+    assert_eq!(vec![bob.clone()], bob_client.get_pending_members());
+
+    let bob_transcript = build_transcript(bob_client, backend);
+    assert_eq!(client.get_members(), bob_transcript.get_members());
+    assert_eq!(vec![bob], bob_transcript.get_pending_members());
+}
+
+/// This test is specific to the MLS implementation and simulates the following:
+/// * create a new client and a transcript, add and cross sign Alice and Bob
+/// * let the client replace Bob with Carol and let Bob propose an update in the same epoch
+/// * let Alice evolve with the replacement whose remove proposal is swapped for Bob's update proposal
+/// * let Alice evolve with the original replacement
+///
+/// The tampered commit is rejected and Alice doesn't keep the carried proposal.
+#[test]
+fn mls_tampered_proposal() {
+    let backend = &EidMlsBackend::default();
+    let client = &mut EidMlsClient::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+    cross_sign(client, &mut transcript, backend);
+
+    let (alice, alice_kp) = EidMlsClient::generate_member("alice".into(), backend);
+    let add_alice_out = client.add(&alice, backend).expect("failed to add member");
+    let add_alice_in = evolve_all(
+        &add_alice_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    let alice_client = &mut EidMlsClient::create_from_invitation(add_alice_in, alice_kp, backend)
+        .expect("failed to create client from invitation");
+    let alice_cross_sign_out = alice_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    evolve_all(
+        &alice_cross_sign_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+
+    let (bob, bob_kp) = EidMlsClient::generate_member("bob".into(), backend);
+    let add_bob_out = client.add(&bob, backend).expect("failed to add member");
+    let add_bob_in = evolve_all(
+        &add_bob_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    let bob_client = &mut EidMlsClient::create_from_invitation(add_bob_in, bob_kp, backend)
+        .expect("failed to create client from invitation");
+    let bob_cross_sign_out = bob_client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    evolve_all(
+        &bob_cross_sign_out,
+        &mut [&mut *client, &mut *alice_client, &mut *bob_client],
+        &mut transcript,
+        backend,
+    );
+
+    let bob_after_insert = client
+        .get_members()
+        .into_iter()
+        .find(|member| member.clone() == bob)
+        .expect("Bob not found");
+    let (carol, _) = EidMlsClient::generate_member("carol".into(), backend);
+    let replace_out = client
+        .replace(&bob_after_insert, &carol, backend)
+        .expect("failed to replace member");
+    let bob_update_out = bob_client
+        .propose_update(backend)
+        .expect("failed to propose update");
+    let mut tampered_out = replace_out.clone();
+    if let (EidMlsEvolvement::OUT { proposals, .. }, EidMlsEvolvement::OUT { message, .. }) =
+        (&mut tampered_out, &bob_update_out)
+    {
+        *proposals = vec![message.clone()];
+    }
+    let tampered_in: EidMlsEvolvement = simulate_transfer(&tampered_out);
+    let epoch = alice_client.epoch();
+    alice_client
+        .evolve(tampered_in, backend)
+        .expect_err("Evolving with a tampered proposal");
+    assert_eq!(0, alice_client.pending_proposals().count());
+    assert_eq!(epoch, alice_client.epoch());
+
+    evolve_all(
+        &replace_out,
+        &mut [&mut *client, &mut *alice_client],
+        &mut transcript,
+        backend,
+    );
+    assert_eq!(0, alice_client.pending_proposals().count());
+    assert!(!alice_client.get_members().contains(&bob));
+    assert_eq!(transcript.get_members(), alice_client.get_members());
+}
+
+/// This test is specific to the MLS implementation and simulates the following:
+/// * create a backend whose config delivers the ratchet tree out of band
+/// * create a new client and a transcript, add a second member
+/// * let the member join without the ratchet tree, expecting an Error
+/// * let the member join with the exported ratchet tree and cross sign
+/// * let a member with the same config join an EID whose invitations carry the ratchet tree
+///
+/// We evolve clients and transcript along the way, comparing their states
+#[test]
+fn mls_config() {
+    let invalid_config = EidMlsConfig {
+        out_of_order_tolerance: 20,
+        maximum_forward_distance: 10,
+        ..EidMlsConfig::default()
+    };
+    let error = EidMlsBackend::default()
+        .with_config(invalid_config)
+        .err()
+        .expect("Using an invalid config");
+    assert!(matches!(error, EidError::InvalidConfigError(..)));
+
+    let config = EidMlsConfig {
+        padding_size: 16,
+        max_past_epochs: 2,
+        use_ratchet_tree_extension: false,
+        ..EidMlsConfig::default()
+    };
+    let backend = &EidMlsBackend::default()
+        .with_config(config)
+        .expect("Failed to configure backend");
+    let client = &mut EidMlsClient::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+
+    cross_sign(client, &mut transcript, backend);
+
+    let (bob, bob_kp) = EidMlsClient::generate_member("bob".into(), backend);
+    let add_bob_evolvement_out = client.add(&bob, backend).expect("failed to add member");
+    let add_bob_evolvement_in: EidMlsEvolvement = simulate_transfer(&add_bob_evolvement_out);
+    transcript
+        .evolve(add_bob_evolvement_in.clone(), backend)
+        .expect("Failed to add evolvement to transcript");
+    client
+        .evolve(add_bob_evolvement_in.clone(), backend)
+        .expect("Failed to evolve");
+
+    let error = EidMlsClient::create_from_invitation(
+        add_bob_evolvement_in.clone(),
+        bob_kp.clone(),
+        backend,
+    )
+    .err()
+    .expect("Joining without ratchet tree");
+    assert!(matches!(error, EidError::InvalidInvitationError(..)));
+
+    let bob_client = &mut EidMlsClient::create_from_invitation_with_ratchet_tree(
+        add_bob_evolvement_in,
+        client.export_ratchet_tree(),
+        bob_kp,
+        backend,
+    )
+    .expect("failed to create client from invitation");
+
+    let cross_sign_evolvement_in = cross_sign(bob_client, &mut transcript, backend);
+    client
+        .evolve(cross_sign_evolvement_in, backend)
+        .expect("Failed to evolve");
+
+    let members = client.get_members();
+    assert!(members.contains(&bob));
+    assert_eq!(2, members.len());
+    assert_eq!(transcript.get_members(), members);
+    assert_eq!(bob_client.get_members(), members);
+
+    // The ratchet tree doesn't need to be delivered out of band if the invitation carries it
+    let default_backend = &EidMlsBackend::default();
+    let default_client =
+        &mut EidMlsClient::generate_initial_client("default_id".into(), default_backend);
+    let (carol, carol_kp) = EidMlsClient::generate_member("carol".into(), backend);
+    let add_carol_evolvement_out = default_client
+        .add(&carol, default_backend)
+        .expect("failed to add member");
+    let add_carol_evolvement_in: EidMlsEvolvement = simulate_transfer(&add_carol_evolvement_out);
+    default_client
+        .evolve(add_carol_evolvement_in.clone(), default_backend)
+        .expect("Failed to evolve");
+    let carol_client =
+        EidMlsClient::create_from_invitation(add_carol_evolvement_in, carol_kp, backend)
+            .expect("failed to create client from invitation");
+    assert_eq!(
+        default_client.get_pending_members(),
+        carol_client.get_pending_members()
+    );
+}
+
+/// This test is specific to the dummy implementation and simulates the following:
+/// * create a new client and a transcript
+/// * let the client cross sign its membership with a forged signature and without a signature, expecting Errors
+/// * let the client cross sign its membership
+///
+/// The cross signature signs the EID identifier and the member's key, so only the original is accepted.
+#[test]
+fn dummy_cross_sign() {
+    let backend = &EidDummyBackend::default();
+    let client = &mut EidDummyClient::generate_initial_client("test_id".into(), backend);
+    let mut transcript = build_transcript(client, backend);
+    assert!(transcript.get_members().is_empty());
+
+    let cross_sign_out = client
+        .cross_sign_membership(backend)
+        .expect("Cross signing failed");
+    for forged_signature in [Some(b"forged".to_vec()), None] {
+        let mut forged_out = cross_sign_out.clone();
+        if let EidDummyEvolvement::Update {
+            cross_signature, ..
+        } = &mut forged_out
+        {
+            *cross_signature = forged_signature;
+        }
+        let forged_in: EidDummyEvolvement = simulate_transfer(&forged_out);
+        let error = transcript
+            .evolve(forged_in, backend)
+            .expect_err("Evolving with a forged cross signature");
+        assert!(matches!(error, EidError::InvalidEvolvementError(..)));
+    }
+    assert!(transcript.get_members().is_empty());
+
+    evolve_all(
+        &cross_sign_out,
+        &mut [&mut *client],
+        &mut transcript,
+        backend,
+    );
+    assert_eq!(1, transcript.get_members().len());
+    assert_eq!(transcript.get_members(), client.get_members());
+}
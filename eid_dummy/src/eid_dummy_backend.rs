@@ -1,4 +1,7 @@
+use sha2::{Digest, Sha256};
+
 use eid_traits::backend::EidBackend;
+use eid_traits::types::EidError;
 
 #[cfg(feature = "test")]
 use crate::eid_dummy_client::EidDummyClient;
@@ -11,4 +14,35 @@ pub struct EidDummyBackend {}
 impl EidBackend for EidDummyBackend {
     #[cfg(feature = "test")]
     type ClientProvider = EidDummyClient;
+
+    /// The dummy backend doesn't hold any key material or configuration.
+    #[cfg(feature = "test")]
+    fn fresh(&self) -> Self {
+        Self::default()
+    }
+
+    /// The dummy key is its own public key.
+    type SigningKeyProvider = Vec<u8>;
+
+    /// Uses SHA-256, so that digests are stable across builds and platforms, e.g. in stored checkpoints.
+    fn hash(&self, data: &[u8]) -> Result<Vec<u8>, EidError> {
+        Ok(Sha256::digest(data).to_vec())
+    }
+
+    fn generate_signing_key(&self) -> Result<Vec<u8>, EidError> {
+        Ok((0..256).map(|_| rand::random::<u8>()).collect())
+    }
+
+    fn public_key(&self, key: &Vec<u8>) -> Vec<u8> {
+        key.clone()
+    }
+
+    /// Dummy signature without cryptographic functionality: the key followed by `data`.
+    fn sign(&self, key: &Vec<u8>, data: &[u8]) -> Result<Vec<u8>, EidError> {
+        Ok([key.as_slice(), data].concat())
+    }
+
+    fn verify_signature(&self, public_key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+        signature == [public_key, data].concat()
+    }
 }
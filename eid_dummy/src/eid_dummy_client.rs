@@ -1,19 +1,28 @@
+use tls_codec::{Serialize, TlsDeserialize, TlsSerialize, TlsSize};
+
 use eid_traits::client::EidClient;
+use eid_traits::evolvement::{EvolvementSummary, EvolvementVerdict};
 use eid_traits::state::EidState;
-use eid_traits::types::EidError;
+use eid_traits::types::{
+    ChallengeResponse, EidChange, EidError, EidId, InvitationExpiry, MembershipEvent,
+};
 
 use crate::eid_dummy_backend::EidDummyBackend;
-use crate::eid_dummy_evolvement::EidDummyEvolvement;
-use crate::eid_dummy_member::{EidDummyMember, BOOLEAN};
-use crate::eid_dummy_state::EidDummyState;
+use crate::eid_dummy_evolvement::{EidDummyEvolvement, EidDummyProposal};
+use crate::eid_dummy_member::{cross_sign_payload, EidDummyMember, BOOLEAN};
+use crate::eid_dummy_proof::EidDummyMembershipProof;
+use crate::eid_dummy_state::{EidDummyInvitation, EidDummyState};
 #[cfg(feature = "test")]
 use crate::eid_dummy_transcript::EidDummyTranscript;
 
 /// # EID Dummy Client
 /// Simple implementation of [EidClient].
+/// Remembers the last evolvement it created that moves the EID to the next count until it is applied.
+#[derive(Clone, TlsSerialize, TlsDeserialize, TlsSize)]
 pub struct EidDummyClient {
     state: EidDummyState,
     id: Vec<u8>,
+    pending: Option<EidDummyEvolvement>,
 }
 
 impl EidClient for EidDummyClient {
@@ -21,146 +30,581 @@ impl EidClient for EidDummyClient {
     type MemberProvider = EidDummyMember;
     type TranscriptStateProvider = EidDummyState;
     type ExportedTranscriptStateProvider = EidDummyState;
+    type ExportedClientStateProvider = EidDummyClient;
+    type MembershipProofProvider = EidDummyMembershipProof;
     type BackendProvider = EidDummyBackend;
-    type KeyProvider = ();
+    /// The dummy doesn't use any key material, a member's client is identified by the member's id.
+    type KeyProvider = Vec<u8>;
 
     // We're only requiring this for tests since we don't want to unnecessarily restrict transcript tue transcript type.
     #[cfg(feature = "test")]
     type TranscriptProvider = EidDummyTranscript;
 
     fn create_eid(
+        eid_id: Option<EidId>,
         initial_member: &Self::MemberProvider,
         _keypair: Self::KeyProvider,
         _backend: &Self::BackendProvider,
     ) -> Result<Self, EidError> {
+        let eid_id =
+            eid_id.unwrap_or_else(|| EidId::new((0..16).map(|_| rand::random::<u8>()).collect()));
         let members = vec![initial_member.clone()];
         let state = EidDummyState {
+            eid_id,
             members,
             evolvement_count: 0,
+            pending_proposals: vec![],
+            invitations: vec![],
         };
         Ok(EidDummyClient {
             state,
             id: initial_member.id.clone(),
+            pending: None,
         })
     }
 
     fn create_from_invitation(
         invitation: Self::EvolvementProvider,
-        _keypair: Self::KeyProvider,
+        id: Self::KeyProvider,
         _backend: &Self::BackendProvider,
     ) -> Result<Self, EidError>
     where
         Self: Sized,
     {
-        if let EidDummyEvolvement::Add {
-            members,
-            invited_id: invited_pk,
-            count,
-        } = invitation
-        {
-            Ok(Self {
-                state: EidDummyState {
-                    members,
-                    evolvement_count: count,
-                },
-                id: invited_pk,
-            })
-        } else {
-            Err(EidError::InvalidInvitationError(
-                "Wrong Evolvement type".into(),
-            ))
+        let (eid_id, members, invited_ids, expiry, invitations, count) = match invitation {
+            EidDummyEvolvement::Add {
+                eid_id,
+                members,
+                invited_ids,
+                expiry,
+                invitations,
+                count,
+                ..
+            } => (eid_id, members, invited_ids, expiry, invitations, count),
+            EidDummyEvolvement::Replace {
+                eid_id,
+                members,
+                invited_id,
+                invitations,
+                count,
+                ..
+            } => (eid_id, members, vec![invited_id], None, invitations, count),
+            _ => {
+                return Err(EidError::InvalidInvitationError(
+                    "Wrong Evolvement type".into(),
+                ))
+            }
+        };
+        if !invited_ids.contains(&id) {
+            return Err(EidError::InvalidInvitationError(
+                "Member is not invited".into(),
+            ));
         }
+        let mut state = EidDummyState {
+            eid_id,
+            members,
+            evolvement_count: count,
+            pending_proposals: vec![],
+            invitations,
+        };
+        // The invitations of members that are no longer pending are dropped, like when applying the evolvement
+        state
+            .invitations
+            .extend(EidDummyInvitation::all(&invited_ids, expiry));
+        let pending_members = state.get_pending_members();
+        state
+            .invitations
+            .retain(|invitation| pending_members.iter().any(|m| m.id == invitation.id));
+        Ok(Self {
+            state,
+            id,
+            pending: None,
+        })
     }
 
     fn add(
         &mut self,
         member: &EidDummyMember,
+        backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        self.add_many(std::slice::from_ref(member), backend)
+    }
+
+    fn remove(
+        &mut self,
+        member: &EidDummyMember,
+        backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        self.remove_many(std::slice::from_ref(member), backend)
+    }
+
+    fn revoke_invitation(
+        &mut self,
+        member: &EidDummyMember,
+        backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.state.get_pending_members().contains(member) {
+            return Err(EidError::InvalidMemberError(
+                "Member has no pending invitation".into(),
+            ));
+        }
+        self.remove_many(std::slice::from_ref(member), backend)
+    }
+
+    fn add_many(
+        &mut self,
+        members: &[EidDummyMember],
         _backend: &EidDummyBackend,
     ) -> Result<EidDummyEvolvement, EidError> {
-        if self.state.members.contains(member) {
-            return Err(EidError::AddMemberError(String::from(
-                "Member already in EID",
-            )));
+        self.invite(members, None)
+    }
+
+    fn add_with_expiry(
+        &mut self,
+        member: &EidDummyMember,
+        expiry: InvitationExpiry,
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        self.invite(std::slice::from_ref(member), Some(expiry))
+    }
+
+    fn remove_many(
+        &mut self,
+        members: &[EidDummyMember],
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if members.is_empty() {
+            return Err(EidError::RemoveMemberError("No members to remove".into()));
         }
         let mut new_state = self.state.clone();
-        new_state.members.push(member.clone());
-        let evolvement = EidDummyEvolvement::Add {
+        for member in members {
+            if let Some(pos) = new_state.members.iter().position(|x| x == member) {
+                new_state.members.swap_remove(pos);
+            } else {
+                return Err(EidError::InvalidMemberError(String::from(
+                    "Member not in EID",
+                )));
+            }
+        }
+
+        let evolvement = EidDummyEvolvement::Remove {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
             members: new_state.members,
-            invited_id: member.id.clone(),
             count: self.state.evolvement_count + 1,
         };
+        self.pending = Some(evolvement.clone());
         Ok(evolvement)
     }
 
-    fn remove(
+    fn replace(
         &mut self,
-        member: &EidDummyMember,
+        old_member: &EidDummyMember,
+        new_member: &EidDummyMember,
         _backend: &EidDummyBackend,
     ) -> Result<EidDummyEvolvement, EidError> {
-        if !self.state.members.contains(member) {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        // The evolvement only contains the replacement, like in MLS
+        if !self.state.pending_proposals.is_empty() {
+            return Err(EidError::CommitError(
+                "There are pending proposals, commit them first".into(),
+            ));
+        }
+        let mut new_state = self.state.clone();
+        if let Some(pos) = new_state.members.iter().position(|x| x == old_member) {
+            new_state.members.swap_remove(pos);
+        } else {
             return Err(EidError::InvalidMemberError(String::from(
                 "Member not in EID",
             )));
         }
-
-        let mut new_state = self.state.clone();
-
-        if let Some(pos) = new_state.members.iter().position(|x| x == member) {
-            new_state.members.swap_remove(pos);
+        if new_state.members.contains(new_member) {
+            return Err(EidError::AddMemberError(String::from(
+                "Member already in EID",
+            )));
         }
+        new_state.members.push(new_member.clone());
 
-        let evolvement = EidDummyEvolvement::Remove {
+        let evolvement = EidDummyEvolvement::Replace {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
             members: new_state.members,
+            replaced_id: old_member.id.clone(),
+            invited_id: new_member.id.clone(),
+            invitations: self.state.invitations.clone(),
             count: self.state.evolvement_count + 1,
         };
+        self.pending = Some(evolvement.clone());
         Ok(evolvement)
     }
 
     fn update(&mut self, _backend: &EidDummyBackend) -> Result<EidDummyEvolvement, EidError> {
-        // create a member with your new pk
-        let new_pk: Vec<u8> = (0..256).map(|_| rand::random::<u8>()).collect();
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let myself = self.updated_self();
+        Ok(self.update_with(myself, None))
+    }
 
-        let mut new_members = self.state.members.clone();
-        // remove yourself from member list
-        let myself = self.state.members.iter().find(|&x| x.id == self.id);
-        let mut myself = myself.unwrap().clone();
-        new_members.retain(|m| &myself != m);
-        myself.cross_signed = BOOLEAN::TRUE;
-        myself.pk = new_pk;
-        new_members.push(myself);
+    fn leave(&mut self, _backend: &EidDummyBackend) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        Ok(EidDummyEvolvement::Proposal {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
+            count: self.state.evolvement_count,
+            proposal: EidDummyProposal::Remove {
+                id: self.id.clone(),
+            },
+        })
+    }
 
-        // create an evolvement with the new member
-        let evolvement = EidDummyEvolvement::Update {
-            members: new_members,
-            count: self.state.evolvement_count + 1,
+    fn propose_add(
+        &mut self,
+        member: &EidDummyMember,
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if self.state.members.iter().any(|m| m.id == member.id) {
+            return Err(EidError::AddMemberError(String::from(
+                "Member already in EID",
+            )));
+        }
+        Ok(EidDummyEvolvement::Proposal {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
+            count: self.state.evolvement_count,
+            proposal: EidDummyProposal::Add {
+                member: member.clone(),
+            },
+        })
+    }
+
+    fn propose_remove(
+        &mut self,
+        member: &EidDummyMember,
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if !self.state.members.contains(member) {
+            return Err(EidError::InvalidMemberError(String::from(
+                "Member not in EID",
+            )));
+        }
+        Ok(EidDummyEvolvement::Proposal {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
+            count: self.state.evolvement_count,
+            proposal: EidDummyProposal::Remove {
+                id: member.id.clone(),
+            },
+        })
+    }
+
+    fn propose_update(
+        &mut self,
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        Ok(EidDummyEvolvement::Proposal {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
+            count: self.state.evolvement_count,
+            proposal: EidDummyProposal::Update {
+                member: self.updated_self(),
+            },
+        })
+    }
+
+    fn commit_pending_proposals(
+        &mut self,
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if self.state.pending_proposals.is_empty() {
+            return Err(EidError::CommitError("No pending proposals".into()));
+        }
+        let mut new_members = self.state.members.clone();
+        let mut invited_ids = vec![];
+        let mut removes = false;
+        for proposal in &self.state.pending_proposals {
+            match proposal {
+                EidDummyProposal::Add { member } => {
+                    if !new_members.iter().any(|m| m.id == member.id) {
+                        invited_ids.push(member.id.clone());
+                        new_members.push(member.clone());
+                    }
+                }
+                EidDummyProposal::Remove { id } => {
+                    removes = true;
+                    new_members.retain(|m| &m.id != id);
+                }
+                EidDummyProposal::Update { member } => {
+                    if let Some(pos) = new_members.iter().position(|m| m.id == member.id) {
+                        new_members[pos] = member.clone();
+                    }
+                }
+            }
+        }
+        let count = self.state.evolvement_count + 1;
+        // Added members join from the commit, so it has to be an invitation
+        let evolvement = if !invited_ids.is_empty() {
+            EidDummyEvolvement::Add {
+                eid_id: self.state.eid_id.clone(),
+                sender: self.id.clone(),
+                members: new_members,
+                invited_ids,
+                expiry: None,
+                invitations: self.state.invitations.clone(),
+                count,
+            }
+        } else if removes {
+            EidDummyEvolvement::Remove {
+                eid_id: self.state.eid_id.clone(),
+                sender: self.id.clone(),
+                members: new_members,
+                count,
+            }
+        } else {
+            EidDummyEvolvement::Update {
+                eid_id: self.state.eid_id.clone(),
+                sender: self.id.clone(),
+                members: new_members,
+                count,
+                cross_signature: None,
+            }
         };
+        self.pending = Some(evolvement.clone());
         Ok(evolvement)
     }
 
+    fn is_active(&self) -> bool {
+        self.state.members.iter().any(|m| m.id == self.id)
+    }
+
     fn evolve(
         &mut self,
         evolvement: EidDummyEvolvement,
         backend: &EidDummyBackend,
-    ) -> Result<(), EidError> {
+    ) -> Result<Vec<MembershipEvent<EidDummyMember>>, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
         //throw error if evolvement count is invalid
-        self.state.apply(evolvement, backend)
+        self.state.verify_eid(&evolvement)?;
+        self.state.verify_epoch(&evolvement)?;
+        if let Some(pending) = &self.pending {
+            let is_commit = !matches!(evolvement, EidDummyEvolvement::Proposal { .. });
+            if is_commit && !Self::is_same_evolvement(pending, &evolvement)? {
+                return Err(EidError::ConflictingEvolvementError(
+                    "Evolvement competes with the pending evolvement".into(),
+                ));
+            }
+        }
+        let count = self.state.evolvement_count;
+        let events = self.state.apply(evolvement, backend)?;
+        if self.state.evolvement_count != count {
+            self.pending = None;
+        }
+        Ok(events)
+    }
+
+    fn validate(
+        &self,
+        evolvement: &EidDummyEvolvement,
+        backend: &EidDummyBackend,
+    ) -> EvolvementVerdict {
+        let mut client = self.clone();
+        match client.evolve(evolvement.clone(), backend) {
+            Ok(_) => EvolvementVerdict::Valid,
+            Err(error) => error.into(),
+        }
+    }
+
+    fn describe(
+        &self,
+        evolvement: &EidDummyEvolvement,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError> {
+        self.state.describe(evolvement, backend)
+    }
+
+    fn batch_evolve(
+        &mut self,
+        evolvements: Vec<EidDummyEvolvement>,
+        backend: &EidDummyBackend,
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        let snapshot = self.clone();
+        let mut events = vec![];
+        for evolvement in evolvements {
+            match self.evolve(evolvement, backend) {
+                Ok(evolvement_events) => events.extend(evolvement_events),
+                Err(error) => {
+                    *self = snapshot;
+                    return Err(error);
+                }
+            }
+        }
+        Ok(events)
     }
 
     fn cross_sign_membership(
         &mut self,
-        backend: &Self::BackendProvider,
+        _backend: &Self::BackendProvider,
     ) -> Result<Self::EvolvementProvider, EidError> {
-        // update yourself in the member list and set cross signed to true
-        self.update(backend)
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        // Sign the EID identifier and our new key and send the signature along with an update
+        let mut myself = self.updated_self();
+        myself.cross_signed = BOOLEAN::TRUE;
+        let payload = cross_sign_payload(&self.state.eid_id, &myself.pk)?;
+        let cross_signature = myself.sign(&payload);
+        Ok(self.update_with(myself, Some(cross_signature)))
     }
 
-    fn get_members(&self) -> Vec<Self::MemberProvider> {
-        self.state
-            .get_members()
+    fn pending_change(&self) -> Option<EidChange<EidDummyMember>> {
+        let members = match self.pending.as_ref()? {
+            EidDummyEvolvement::Add { members, .. }
+            | EidDummyEvolvement::Update { members, .. }
+            | EidDummyEvolvement::Remove { members, .. }
+            | EidDummyEvolvement::Replace { members, .. } => members,
+            EidDummyEvolvement::Proposal { .. } => return None,
+        };
+        let added = match self.pending.as_ref()? {
+            EidDummyEvolvement::Add { invited_ids, .. } => members
+                .iter()
+                .filter(|m| invited_ids.contains(&m.id))
+                .cloned()
+                .collect(),
+            EidDummyEvolvement::Replace { invited_id, .. } => members
+                .iter()
+                .filter(|m| &m.id == invited_id)
+                .cloned()
+                .collect(),
+            _ => vec![],
+        };
+        let removed: Vec<_> = self
+            .state
+            .members
             .iter()
-            .filter(|&m| m.cross_signed == BOOLEAN::TRUE)
+            .filter(|m| !members.contains(m))
             .cloned()
-            .collect()
+            .collect();
+        let myself = self.state.members.iter().find(|m| m.id == self.id);
+        let updated_myself = members.iter().find(|m| m.id == self.id);
+        let self_update = added.is_empty()
+            && removed.is_empty()
+            && matches!((myself, updated_myself), (Some(old), Some(new)) if old.pk != new.pk);
+        Some(EidChange {
+            added,
+            removed,
+            self_update,
+        })
+    }
+
+    fn resolve_conflict(
+        &mut self,
+        evolvement: EidDummyEvolvement,
+        backend: &EidDummyBackend,
+    ) -> Result<Option<EidChange<EidDummyMember>>, EidError> {
+        let change = self.pending_change();
+        let pending = self.pending.take();
+        if let Err(error) = self.evolve(evolvement, backend) {
+            self.pending = pending;
+            return Err(error);
+        }
+        Ok(change)
+    }
+
+    fn recreate(
+        &mut self,
+        change: &EidChange<EidDummyMember>,
+        backend: &EidDummyBackend,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let added: Vec<_> = change
+            .added
+            .iter()
+            .filter(|&m| !self.state.members.contains(m))
+            .cloned()
+            .collect();
+        let removed: Vec<_> = change
+            .removed
+            .iter()
+            .filter(|&m| self.state.members.contains(m))
+            .cloned()
+            .collect();
+        if added.is_empty() && removed.is_empty() {
+            // A self update of a member that hasn't cross-signed yet was its cross sign
+            return if change.self_update && !self.has_cross_signed() {
+                self.cross_sign_membership(backend)
+            } else if change.self_update {
+                self.update(backend)
+            } else {
+                Err(EidError::CommitError("Nothing to re-create".into()))
+            };
+        }
+
+        let mut new_members = self.state.members.clone();
+        new_members.retain(|m| !removed.contains(m));
+        new_members.extend(added.iter().cloned());
+        let count = self.state.evolvement_count + 1;
+        let evolvement = if added.is_empty() {
+            EidDummyEvolvement::Remove {
+                eid_id: self.state.eid_id.clone(),
+                sender: self.id.clone(),
+                members: new_members,
+                count,
+            }
+        } else {
+            EidDummyEvolvement::Add {
+                eid_id: self.state.eid_id.clone(),
+                sender: self.id.clone(),
+                members: new_members,
+                invited_ids: added.iter().map(|member| member.id.clone()).collect(),
+                expiry: None,
+                invitations: self.state.invitations.clone(),
+                count,
+            }
+        };
+        self.pending = Some(evolvement.clone());
+        Ok(evolvement)
+    }
+
+    fn get_members(&self) -> Vec<Self::MemberProvider> {
+        self.state.get_members()
+    }
+
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider> {
+        self.state.get_pending_members()
+    }
+
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider> {
+        self.state.get_expired_members(now)
+    }
+
+    fn epoch(&self) -> u64 {
+        self.state.epoch()
+    }
+    fn eid_id(&self) -> EidId {
+        self.state.eid_id()
     }
     fn export_transcript_state(
         &self,
@@ -169,6 +613,55 @@ impl EidClient for EidDummyClient {
         Ok(self.state.clone())
     }
 
+    fn prove_membership(
+        &self,
+        _backend: &Self::BackendProvider,
+    ) -> Result<EidDummyMembershipProof, EidError> {
+        if !self.state.get_members().iter().any(|m| m.id == self.id) {
+            return Err(EidError::InactiveClientError(
+                "Client is not a cross-signed member".into(),
+            ));
+        }
+        Ok(EidDummyMembershipProof {
+            eid_id: self.state.eid_id.clone(),
+            count: self.state.evolvement_count,
+            members: self.state.members.clone(),
+            id: self.id.clone(),
+        })
+    }
+
+    fn sign_challenge(
+        &self,
+        nonce: &[u8],
+        _backend: &Self::BackendProvider,
+    ) -> Result<ChallengeResponse, EidError> {
+        let myself = self
+            .state
+            .members
+            .iter()
+            .find(|m| m.id == self.id)
+            .ok_or_else(|| EidError::InactiveClientError("Client is inactive".into()))?;
+        let content = ChallengeResponse::signed_content(&self.state.eid_id, nonce)?;
+        Ok(ChallengeResponse {
+            signature_key: myself.pk.clone(),
+            signature: myself.sign(&content),
+        })
+    }
+
+    fn export_client_state(
+        &self,
+        _backend: &Self::BackendProvider,
+    ) -> Result<EidDummyClient, EidError> {
+        Ok(self.clone())
+    }
+
+    fn restore_client_state(
+        state: EidDummyClient,
+        _backend: &Self::BackendProvider,
+    ) -> Result<Self, EidError> {
+        Ok(state)
+    }
+
     #[cfg(feature = "test")]
     fn generate_member(
         id: Vec<u8>,
@@ -176,17 +669,111 @@ impl EidClient for EidDummyClient {
     ) -> (Self::MemberProvider, Self::KeyProvider) {
         (
             EidDummyMember {
-                id,
+                id: id.clone(),
                 pk: (0..256).map(|_| rand::random::<u8>()).collect(),
                 cross_signed: BOOLEAN::FALSE,
             },
-            (),
+            id,
         )
     }
 
     #[cfg(feature = "test")]
     fn generate_initial_client(id: Vec<u8>, backend: &Self::BackendProvider) -> Self {
         let (member, keypair) = EidDummyClient::generate_member(id, backend);
-        EidDummyClient::create_eid(&member, keypair, backend).expect("Could not create EID")
+        EidDummyClient::create_eid(None, &member, keypair, backend).expect("Could not create EID")
+    }
+}
+
+impl EidDummyClient {
+    /// Create an evolvement that adds `members`, whose invitations expire at `expiry`.
+    fn invite(
+        &mut self,
+        members: &[EidDummyMember],
+        expiry: Option<InvitationExpiry>,
+    ) -> Result<EidDummyEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if members.is_empty() {
+            return Err(EidError::AddMemberError("No members to add".into()));
+        }
+        let mut new_state = self.state.clone();
+        for member in members {
+            if new_state.members.contains(member) {
+                return Err(EidError::AddMemberError(String::from(
+                    "Member already in EID",
+                )));
+            }
+            new_state.members.push(member.clone());
+        }
+        let evolvement = EidDummyEvolvement::Add {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
+            members: new_state.members,
+            invited_ids: members.iter().map(|member| member.id.clone()).collect(),
+            expiry,
+            invitations: self.state.invitations.clone(),
+            count: self.state.evolvement_count + 1,
+        };
+        self.pending = Some(evolvement.clone());
+        Ok(evolvement)
+    }
+
+    /// Compare two evolvements by their serialization, since [EidDummyMember]s are only compared by id.
+    fn is_same_evolvement(
+        evolvement: &EidDummyEvolvement,
+        other: &EidDummyEvolvement,
+    ) -> Result<bool, EidError> {
+        let serialized = evolvement
+            .tls_serialize_detached()
+            .map_err(|e| EidError::SerializationError(e.to_string()))?;
+        let other_serialized = other
+            .tls_serialize_detached()
+            .map_err(|e| EidError::SerializationError(e.to_string()))?;
+        Ok(serialized == other_serialized)
+    }
+
+    /// True if the client has cross-signed its membership.
+    fn has_cross_signed(&self) -> bool {
+        self.state
+            .members
+            .iter()
+            .any(|m| m.id == self.id && m.cross_signed == BOOLEAN::TRUE)
+    }
+
+    /// Create a copy of your own member with a new pk.
+    fn updated_self(&self) -> EidDummyMember {
+        let mut myself = self
+            .state
+            .members
+            .iter()
+            .find(|&x| x.id == self.id)
+            .expect("An active client is a member")
+            .clone();
+        myself.pk = (0..256).map(|_| rand::random::<u8>()).collect();
+        myself
+    }
+
+    /// Create an update that replaces your own member with `myself`, optionally carrying a `cross_signature`.
+    fn update_with(
+        &mut self,
+        myself: EidDummyMember,
+        cross_signature: Option<Vec<u8>>,
+    ) -> EidDummyEvolvement {
+        let mut new_members = self.state.members.clone();
+        // remove yourself from member list
+        new_members.retain(|m| m.id != myself.id);
+        new_members.push(myself);
+
+        // create an evolvement with the new member
+        let evolvement = EidDummyEvolvement::Update {
+            eid_id: self.state.eid_id.clone(),
+            sender: self.id.clone(),
+            members: new_members,
+            count: self.state.evolvement_count + 1,
+            cross_signature,
+        };
+        self.pending = Some(evolvement.clone());
+        evolvement
     }
 }
@@ -1,27 +1,99 @@
 use tls_codec::{TlsDeserialize, TlsSerialize, TlsSize};
 
 use eid_traits::evolvement::Evolvement;
+use eid_traits::types::{EidId, InvitationExpiry};
 
 use crate::eid_dummy_member::EidDummyMember;
+use crate::eid_dummy_state::EidDummyInvitation;
 
 /// # EidDummyEvolvement
 /// Dummy Implementation of [Evolvement]
-#[derive(Debug, Clone, TlsSerialize, TlsDeserialize, TlsSize)]
+#[derive(Debug, Clone, PartialEq, TlsSerialize, TlsDeserialize, TlsSize)]
 #[repr(u8)]
 pub enum EidDummyEvolvement {
     Add {
+        /// Id of the EID the evolvement belongs to.
+        eid_id: EidId,
         count: u64,
+        /// Id of the member that created the evolvement.
+        sender: Vec<u8>,
         members: Vec<EidDummyMember>,
-        invited_id: Vec<u8>,
+        invited_ids: Vec<Vec<u8>>,
+        /// Expiry of the invitations of the invited members.
+        expiry: Option<InvitationExpiry>,
+        /// Invitations of the members that were pending before, so that invited members learn them.
+        invitations: Vec<EidDummyInvitation>,
     },
     Update {
+        eid_id: EidId,
         count: u64,
+        sender: Vec<u8>,
         members: Vec<EidDummyMember>,
+        /// Signature of the sender if it cross-signs its membership with the update.
+        cross_signature: Option<Vec<u8>>,
     },
     Remove {
+        eid_id: EidId,
         count: u64,
+        sender: Vec<u8>,
         members: Vec<EidDummyMember>,
     },
+    /// Removes the member with `replaced_id` and invites the member with `invited_id` in a single evolvement.
+    Replace {
+        eid_id: EidId,
+        count: u64,
+        sender: Vec<u8>,
+        members: Vec<EidDummyMember>,
+        replaced_id: Vec<u8>,
+        invited_id: Vec<u8>,
+        invitations: Vec<EidDummyInvitation>,
+    },
+    /// A proposal doesn't change the member list until it is committed,
+    /// so its count is the count of the state it is proposed for.
+    Proposal {
+        eid_id: EidId,
+        count: u64,
+        sender: Vec<u8>,
+        proposal: EidDummyProposal,
+    },
+}
+
+/// # EidDummyProposal
+/// A change to the member list that is proposed by one member and committed by another.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+#[repr(u8)]
+pub enum EidDummyProposal {
+    Add {
+        member: EidDummyMember,
+    },
+    Remove {
+        id: Vec<u8>,
+    },
+    /// Replaces the member with the same id by `member`.
+    Update {
+        member: EidDummyMember,
+    },
 }
 
-impl Evolvement for EidDummyEvolvement {}
+impl Evolvement for EidDummyEvolvement {
+    fn target_epoch(&self) -> u64 {
+        match self {
+            // The count of a commit is the count of the state after applying it
+            EidDummyEvolvement::Add { count, .. }
+            | EidDummyEvolvement::Update { count, .. }
+            | EidDummyEvolvement::Remove { count, .. }
+            | EidDummyEvolvement::Replace { count, .. } => count.saturating_sub(1),
+            EidDummyEvolvement::Proposal { count, .. } => *count,
+        }
+    }
+
+    fn eid_id(&self) -> EidId {
+        match self {
+            EidDummyEvolvement::Add { eid_id, .. }
+            | EidDummyEvolvement::Update { eid_id, .. }
+            | EidDummyEvolvement::Remove { eid_id, .. }
+            | EidDummyEvolvement::Replace { eid_id, .. }
+            | EidDummyEvolvement::Proposal { eid_id, .. } => eid_id.clone(),
+        }
+    }
+}
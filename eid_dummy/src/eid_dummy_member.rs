@@ -1,6 +1,10 @@
-use tls_codec::{TlsDeserialize, TlsSerialize, TlsSize};
+use tls_codec::{Serialize, TlsDeserialize, TlsSerialize, TlsSize};
 
+use eid_traits::checkpoint;
 use eid_traits::member::Member;
+use eid_traits::types::{EidError, EidId};
+
+use crate::eid_dummy_backend::EidDummyBackend;
 
 /// # EidDummyMember
 /// Dummy Implementation of [Member]
@@ -40,3 +44,47 @@ impl Member for EidDummyMember {
         self.pk.clone()
     }
 }
+
+impl EidDummyMember {
+    /// Dummy signature without cryptographic functionality: the member's public key followed by the content.
+    pub(crate) fn sign(&self, content: &[u8]) -> Vec<u8> {
+        [self.pk.as_slice(), content].concat()
+    }
+}
+
+/// The content a member signs to cross-sign its membership: the EID identifier and the member's key.
+#[derive(TlsSerialize, TlsSize)]
+struct CrossSignContent {
+    label: Vec<u8>,
+    eid_id: EidId,
+    member_key: Vec<u8>,
+}
+
+/// Get the payload a member with key `member_key` signs to cross-sign its membership in the EID with identifier `eid_id`.
+/// It is labeled like the one of the MLS implementation, since the same key signs challenges.
+pub(crate) fn cross_sign_payload(eid_id: &EidId, member_key: &[u8]) -> Result<Vec<u8>, EidError> {
+    CrossSignContent {
+        label: b"EID cross-sign".to_vec(),
+        eid_id: eid_id.clone(),
+        member_key: member_key.to_vec(),
+    }
+    .tls_serialize_detached()
+    .map_err(|e| EidError::SerializationError(e.to_string()))
+}
+
+/// Get the digest of `members` of an EID, identified by their id and public key
+/// (see [checkpoint::members_digest]).
+pub(crate) fn members_digest(
+    members: &[EidDummyMember],
+    backend: &EidDummyBackend,
+) -> Result<Vec<u8>, EidError> {
+    let members = members
+        .iter()
+        .map(|member| {
+            vec![member.id.clone(), member.pk.clone()]
+                .tls_serialize_detached()
+                .map_err(|e| EidError::SerializationError(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    checkpoint::members_digest(backend, members)
+}
@@ -0,0 +1,103 @@
+use tls_codec::{TlsDeserialize, TlsSerialize, TlsSize};
+
+use eid_traits::checkpoint::Checkpoint;
+use eid_traits::proof::EidMembershipProof;
+use eid_traits::state::EidState;
+use eid_traits::types::{EidError, EidId};
+
+use crate::eid_dummy_backend::EidDummyBackend;
+use crate::eid_dummy_member::{members_digest, EidDummyMember, BOOLEAN};
+use crate::eid_dummy_state::EidDummyState;
+
+/// # EID Dummy Membership Proof
+/// Dummy implementation of [EidMembershipProof] without cryptographic functionality.
+/// Holds the members of the EID in the proven epoch and the id of the member whose membership is proven.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidDummyMembershipProof {
+    pub(crate) eid_id: EidId,
+    pub(crate) count: u64,
+    pub(crate) members: Vec<EidDummyMember>,
+    pub(crate) id: Vec<u8>,
+}
+
+impl EidMembershipProof for EidDummyMembershipProof {
+    type MemberProvider = EidDummyMember;
+    type StateProvider = EidDummyState;
+    type BackendProvider = EidDummyBackend;
+
+    fn eid_id(&self) -> EidId {
+        self.eid_id.clone()
+    }
+
+    fn epoch(&self) -> u64 {
+        self.count
+    }
+
+    fn verify(
+        &self,
+        checkpoint: &Checkpoint,
+        backend: &EidDummyBackend,
+    ) -> Result<EidDummyMember, EidError> {
+        self.verify_eid(&checkpoint.eid_id, checkpoint.epoch)?;
+        if members_digest(&self.cross_signed_members(), backend)? != checkpoint.members_digest {
+            return Err(EidError::InvalidMembershipProofError(
+                "Proof doesn't show the members of the checkpoint".into(),
+            ));
+        }
+        self.member()
+    }
+
+    fn verify_with_state(
+        &self,
+        state: &EidDummyState,
+        backend: &EidDummyBackend,
+    ) -> Result<EidDummyMember, EidError> {
+        self.verify_eid(&state.eid_id, state.evolvement_count)?;
+        // Members are equal if their ids are, so their public keys are compared by the digest
+        if members_digest(&self.cross_signed_members(), backend)?
+            != members_digest(&state.get_members(), backend)?
+        {
+            return Err(EidError::InvalidMembershipProofError(
+                "Proof doesn't show the members of the state".into(),
+            ));
+        }
+        self.member()
+    }
+}
+
+impl EidDummyMembershipProof {
+    /// Check that the proof claims membership in the EID with identifier `eid_id` in `epoch`.
+    fn verify_eid(&self, eid_id: &EidId, epoch: u64) -> Result<(), EidError> {
+        if &self.eid_id != eid_id {
+            return Err(EidError::InvalidMembershipProofError(
+                "Proof belongs to a different EID".into(),
+            ));
+        }
+        if self.count != epoch {
+            return Err(EidError::InvalidMembershipProofError(format!(
+                "Proof is for epoch {}, expected epoch {}",
+                self.count, epoch
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the members of the proof that have cross-signed their membership.
+    fn cross_signed_members(&self) -> Vec<EidDummyMember> {
+        self.members
+            .iter()
+            .filter(|member| member.cross_signed == BOOLEAN::TRUE)
+            .cloned()
+            .collect()
+    }
+
+    /// Get the cross-signed member whose membership is proven.
+    fn member(&self) -> Result<EidDummyMember, EidError> {
+        self.cross_signed_members()
+            .into_iter()
+            .find(|member| member.id == self.id)
+            .ok_or_else(|| {
+                EidError::InvalidMembershipProofError("Member is not part of the EID".into())
+            })
+    }
+}
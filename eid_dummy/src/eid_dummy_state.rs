@@ -1,22 +1,53 @@
 use tls_codec::{TlsDeserialize, TlsSerialize, TlsSize};
 
+use eid_traits::backend::EidBackend;
+use eid_traits::evolvement::{EvolvementKind, EvolvementSummary, EvolvementVerdict};
 use eid_traits::state::EidState;
 use eid_traits::transcript::EidExportedTranscriptState;
-use eid_traits::types::EidError;
 use eid_traits::types::EidError::InvalidEvolvementError;
+use eid_traits::types::{ChallengeResponse, EidError, EidId, InvitationExpiry, MembershipEvent};
 
 use crate::eid_dummy_backend::EidDummyBackend;
-use crate::eid_dummy_evolvement::EidDummyEvolvement;
-use crate::eid_dummy_member::EidDummyMember;
+use crate::eid_dummy_evolvement::{EidDummyEvolvement, EidDummyProposal};
+use crate::eid_dummy_member::{cross_sign_payload, EidDummyMember, BOOLEAN};
 
 /// # EID Dummy State
 /// Dummy implementation of [EidState].
 /// Holds [EidDummyMember]s and a count of processed [Evolvement]s for a minimum of validation
 /// to provide compliance with integration tests.
+/// Proposals that were received since the last count are kept until they are committed.
+/// Invitations with an expiry are kept until the invited member cross-signs or is removed.
 #[derive(Default, Debug, Clone, PartialEq, Eq, TlsDeserialize, TlsSerialize, TlsSize)]
 pub struct EidDummyState {
+    pub(crate) eid_id: EidId,
     pub(crate) members: Vec<EidDummyMember>,
     pub(crate) evolvement_count: u64,
+    pub(crate) pending_proposals: Vec<EidDummyProposal>,
+    pub(crate) invitations: Vec<EidDummyInvitation>,
+}
+
+/// # EidDummyInvitation
+/// The expiry of the invitation of a pending member.
+#[derive(Debug, Clone, PartialEq, Eq, TlsDeserialize, TlsSerialize, TlsSize)]
+pub struct EidDummyInvitation {
+    pub(crate) id: Vec<u8>,
+    pub(crate) expiry: InvitationExpiry,
+}
+
+impl EidDummyInvitation {
+    /// Get the invitations of all `invited_ids` if they expire.
+    pub(crate) fn all(invited_ids: &[Vec<u8>], expiry: Option<InvitationExpiry>) -> Vec<Self> {
+        match expiry {
+            Some(expiry) => invited_ids
+                .iter()
+                .map(|id| Self {
+                    id: id.clone(),
+                    expiry,
+                })
+                .collect(),
+            None => vec![],
+        }
+    }
 }
 
 impl EidState for EidDummyState {
@@ -27,24 +58,299 @@ impl EidState for EidDummyState {
     fn apply(
         &mut self,
         evolvement: EidDummyEvolvement,
-        _backend: &EidDummyBackend,
-    ) -> Result<(), EidError> {
+        backend: &EidDummyBackend,
+    ) -> Result<Vec<MembershipEvent<EidDummyMember>>, EidError> {
+        self.verify_eid(&evolvement)?;
+        self.verify_epoch(&evolvement)?;
         match &evolvement {
-            EidDummyEvolvement::Update { members, count, .. }
-            | EidDummyEvolvement::Add { members, count, .. }
-            | EidDummyEvolvement::Remove { members, count, .. } => {
+            EidDummyEvolvement::Update {
+                members,
+                count,
+                sender,
+                ..
+            }
+            | EidDummyEvolvement::Add {
+                members,
+                count,
+                sender,
+                ..
+            }
+            | EidDummyEvolvement::Remove {
+                members,
+                count,
+                sender,
+                ..
+            }
+            | EidDummyEvolvement::Replace {
+                members,
+                count,
+                sender,
+                ..
+            } => {
                 if self.evolvement_count + 1 != *count {
                     return Err(InvalidEvolvementError("Invalid Evolvement count".into()));
                 }
+                if self
+                    .get_expired_members(None)
+                    .iter()
+                    .any(|m| &m.id == sender)
+                {
+                    return Err(InvalidEvolvementError(
+                        "The invitation of the sender has expired".into(),
+                    ));
+                }
+                let cross_signature = match &evolvement {
+                    EidDummyEvolvement::Update {
+                        cross_signature, ..
+                    } => cross_signature.as_ref(),
+                    _ => None,
+                };
+                self.verify_cross_signature(sender, members, cross_signature, backend)?;
                 self.evolvement_count += 1;
+                let events = self.membership_events(members);
                 self.members = members.clone();
-                Ok(())
+                self.pending_proposals.clear();
+                if let EidDummyEvolvement::Add {
+                    invited_ids,
+                    expiry,
+                    ..
+                } = &evolvement
+                {
+                    self.invitations
+                        .extend(EidDummyInvitation::all(invited_ids, *expiry));
+                }
+                let pending_members = self.get_pending_members();
+                self.invitations
+                    .retain(|invitation| pending_members.iter().any(|m| m.id == invitation.id));
+                Ok(events)
             }
+            EidDummyEvolvement::Proposal { proposal, .. } => {
+                if !self.pending_proposals.contains(proposal) {
+                    self.pending_proposals.push(proposal.clone());
+                }
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// Apply the evolvement to a copy of the state.
+    /// Dummy evolvements aren't signed, so only the epoch and count are validated.
+    fn validate(
+        &self,
+        evolvement: &EidDummyEvolvement,
+        backend: &EidDummyBackend,
+    ) -> EvolvementVerdict {
+        let mut state = self.clone();
+        match state.apply(evolvement.clone(), backend) {
+            Ok(_) => EvolvementVerdict::Valid,
+            Err(error) => error.into(),
+        }
+    }
+
+    fn describe(
+        &self,
+        evolvement: &EidDummyEvolvement,
+        backend: &EidDummyBackend,
+    ) -> Result<EvolvementSummary<EidDummyMember>, EidError> {
+        let mut state = self.clone();
+        state.apply(evolvement.clone(), backend)?;
+        match evolvement {
+            EidDummyEvolvement::Proposal {
+                sender, proposal, ..
+            } => {
+                let (added, removed) = match proposal {
+                    EidDummyProposal::Add { member } => (vec![member.clone()], vec![]),
+                    EidDummyProposal::Remove { id } => (vec![], self.members_with_id(id)),
+                    EidDummyProposal::Update { .. } => (vec![], vec![]),
+                };
+                Ok(EvolvementSummary {
+                    kind: EvolvementKind::Proposal,
+                    sender: self.members_with_id(sender).pop(),
+                    added,
+                    removed,
+                    epoch: state.epoch(),
+                })
+            }
+            EidDummyEvolvement::Update { sender, .. }
+            | EidDummyEvolvement::Add { sender, .. }
+            | EidDummyEvolvement::Remove { sender, .. }
+            | EidDummyEvolvement::Replace { sender, .. } => {
+                let sender = self.members_with_id(sender).pop();
+                let added: Vec<_> = state
+                    .members
+                    .iter()
+                    .filter(|member| !self.members.contains(member))
+                    .cloned()
+                    .collect();
+                let removed: Vec<_> = self
+                    .members
+                    .iter()
+                    .filter(|member| !state.members.contains(member))
+                    .cloned()
+                    .collect();
+                let cross_signs = matches!(
+                    evolvement,
+                    EidDummyEvolvement::Update {
+                        cross_signature: Some(_),
+                        ..
+                    }
+                );
+                Ok(EvolvementSummary {
+                    kind: EvolvementKind::of_commit(
+                        !added.is_empty(),
+                        !removed.is_empty(),
+                        cross_signs,
+                    ),
+                    sender,
+                    added,
+                    removed,
+                    epoch: state.epoch(),
+                })
+            }
+        }
+    }
+
+    fn epoch(&self) -> u64 {
+        self.evolvement_count
+    }
+
+    fn eid_id(&self) -> EidId {
+        self.eid_id.clone()
+    }
+
+    fn verify_challenge(
+        &self,
+        nonce: &[u8],
+        response: &ChallengeResponse,
+        _backend: &EidDummyBackend,
+    ) -> Result<EidDummyMember, EidError> {
+        let member = self
+            .get_members()
+            .into_iter()
+            .find(|member| member.pk == response.signature_key)
+            .ok_or_else(|| {
+                EidError::InvalidChallengeResponseError("Signer is not a member of the EID".into())
+            })?;
+        let content = ChallengeResponse::signed_content(&self.eid_id, nonce)?;
+        if response.signature == member.sign(&content) {
+            Ok(member)
+        } else {
+            Err(EidError::InvalidChallengeResponseError(
+                "Invalid signature".into(),
+            ))
         }
     }
 
     fn get_members(&self) -> Vec<EidDummyMember> {
-        self.members.clone()
+        self.members
+            .iter()
+            .filter(|&m| m.cross_signed == BOOLEAN::TRUE)
+            .cloned()
+            .collect()
+    }
+
+    fn get_pending_members(&self) -> Vec<EidDummyMember> {
+        self.members
+            .iter()
+            .filter(|&m| m.cross_signed == BOOLEAN::FALSE)
+            .cloned()
+            .collect()
+    }
+
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<EidDummyMember> {
+        self.get_pending_members()
+            .into_iter()
+            .filter(|member| {
+                self.invitations.iter().any(|invitation| {
+                    invitation.id == member.id
+                        && invitation.expiry.is_expired(self.evolvement_count, now)
+                })
+            })
+            .collect()
+    }
+}
+
+impl EidDummyState {
+    /// Diff the current member list with the member list after an evolvement.
+    fn membership_events(
+        &self,
+        new_members: &[EidDummyMember],
+    ) -> Vec<MembershipEvent<EidDummyMember>> {
+        let mut events: Vec<_> = self
+            .members
+            .iter()
+            .filter(|member| !new_members.contains(member))
+            .cloned()
+            .map(MembershipEvent::MemberRemoved)
+            .collect();
+        for new_member in new_members {
+            match self.members.iter().find(|member| *member == new_member) {
+                None => events.push(MembershipEvent::MemberAdded(new_member.clone())),
+                Some(member) if member.cross_signed != new_member.cross_signed => {
+                    events.push(MembershipEvent::MemberCrossSigned(new_member.clone()))
+                }
+                Some(member) if member.pk != new_member.pk => {
+                    events.push(MembershipEvent::MemberUpdatedKey(new_member.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        events
+    }
+
+    /// Verify that a member only becomes cross-signed by its own cross signature,
+    /// which signs the EID identifier and the member's new key (see [cross_sign_payload]).
+    ///
+    /// # Arguments
+    ///
+    /// * `sender`: The id of the member that created the evolvement
+    /// * `members`: The members after the evolvement
+    /// * `cross_signature`: The cross signature the evolvement carries
+    /// * `backend`: The [EidDummyBackend]
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidEvolvementError] if a member becomes cross-signed
+    /// without a valid cross signature.
+    fn verify_cross_signature(
+        &self,
+        sender: &[u8],
+        members: &[EidDummyMember],
+        cross_signature: Option<&Vec<u8>>,
+        backend: &EidDummyBackend,
+    ) -> Result<(), EidError> {
+        if let Some(cross_signature) = cross_signature {
+            let member = members
+                .iter()
+                .find(|m| m.id == sender && m.cross_signed == BOOLEAN::TRUE)
+                .ok_or_else(|| {
+                    InvalidEvolvementError("The sender doesn't cross-sign its membership".into())
+                })?;
+            let payload = cross_sign_payload(&self.eid_id, &member.pk)?;
+            if !backend.verify_signature(&member.pk, &payload, cross_signature) {
+                return Err(InvalidEvolvementError("Invalid cross signature".into()));
+            }
+        }
+        let cross_signed_without_signature = members.iter().any(|member| {
+            member.cross_signed == BOOLEAN::TRUE
+                && !(cross_signature.is_some() && member.id == sender)
+                && !self
+                    .members
+                    .iter()
+                    .any(|m| m == member && m.cross_signed == BOOLEAN::TRUE)
+        });
+        if cross_signed_without_signature {
+            return Err(InvalidEvolvementError(
+                "A member is cross-signed without a cross signature".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn members_with_id(&self, id: &[u8]) -> Vec<EidDummyMember> {
+        self.members
+            .iter()
+            .filter(|member| member.id == id)
+            .cloned()
+            .collect()
     }
 }
 
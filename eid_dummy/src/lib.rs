@@ -4,5 +4,6 @@ pub mod eid_dummy_backend;
 pub mod eid_dummy_client;
 pub mod eid_dummy_evolvement;
 pub mod eid_dummy_member;
+pub mod eid_dummy_proof;
 pub mod eid_dummy_state;
 pub mod eid_dummy_transcript;
@@ -1,32 +1,195 @@
-use openmls_rust_crypto::OpenMlsRustCrypto;
-#[cfg(feature = "test")]
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_rust_crypto::RustCrypto;
+use openmls_traits::crypto::OpenMlsCrypto;
+use openmls_traits::signatures::Signer;
 use openmls_traits::types::Ciphersuite;
+use openmls_traits::OpenMlsCryptoProvider;
 
 use eid_traits::backend::EidBackend;
+use eid_traits::types::EidError;
 
 #[cfg(feature = "test")]
 use crate::eid_mls_client::EidMlsClient;
+use crate::eid_mls_config::EidMlsConfig;
+use crate::eid_mls_key_store::EidMlsKeyStore;
+use crate::eid_mls_storage::{EidMlsMemoryStorage, EidMlsStorage};
+
+/// The [Ciphersuite] an [EidMlsBackend] uses if no other ciphersuite is chosen.
+pub const DEFAULT_CIPHERSUITE: Ciphersuite =
+    Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519;
 
 /// # EID MLS Backend
 /// Implements [EidBackend] using [openmls]
-#[cfg_attr(not(feature = "test"), derive(Default))]
 pub struct EidMlsBackend {
-    pub(crate) mls_backend: OpenMlsRustCrypto,
-    #[cfg(feature = "test")]
-    pub ciphersuite: Ciphersuite,
+    pub(crate) mls_backend: EidMlsCryptoProvider,
+    ciphersuite: Ciphersuite,
+    config: EidMlsConfig,
 }
 
-#[cfg(feature = "test")]
 impl Default for EidMlsBackend {
     fn default() -> Self {
-        Self {
-            mls_backend: OpenMlsRustCrypto::default(),
-            ciphersuite: Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519,
-        }
+        Self::with_storage(EidMlsMemoryStorage::default())
+            .expect("The default ciphersuite is always supported")
+    }
+}
+
+impl EidMlsBackend {
+    /// Create an [EidMlsBackend] that uses `ciphersuite` for all new EIDs, credentials and key packages
+    /// and keeps its key material in `storage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ciphersuite`: The [Ciphersuite]
+    /// * `storage`: The [EidMlsStorage]
+    ///
+    /// returns: [Result]<[Self], [EidError]> [EidError] if the crypto provider doesn't support the ciphersuite.
+    pub fn new(
+        ciphersuite: Ciphersuite,
+        storage: impl EidMlsStorage + 'static,
+    ) -> Result<Self, EidError> {
+        let mls_backend = EidMlsCryptoProvider {
+            crypto: RustCrypto::default(),
+            key_store: EidMlsKeyStore::new(storage),
+        };
+        mls_backend.crypto().supports(ciphersuite).map_err(|_| {
+            EidError::UnsupportedCiphersuiteError(format!(
+                "Ciphersuite {:?} is not supported by the crypto provider",
+                ciphersuite
+            ))
+        })?;
+        Ok(Self {
+            mls_backend,
+            ciphersuite,
+            config: EidMlsConfig::default(),
+        })
+    }
+
+    /// Create an [EidMlsBackend] that uses `ciphersuite` and keeps its key material in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `ciphersuite`: The [Ciphersuite]
+    ///
+    /// returns: [Result]<[Self], [EidError]> [EidError] if the crypto provider doesn't support the ciphersuite.
+    pub fn with_ciphersuite(ciphersuite: Ciphersuite) -> Result<Self, EidError> {
+        Self::new(ciphersuite, EidMlsMemoryStorage::default())
+    }
+
+    /// Create an [EidMlsBackend] that uses the [DEFAULT_CIPHERSUITE] and keeps its key material in `storage`.
+    /// Use e.g. an [EidMlsFileStorage](crate::eid_mls_storage::EidMlsFileStorage) to let
+    /// credentials and key packages survive process restarts.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage`: The [EidMlsStorage]
+    ///
+    /// returns: [Result]<[Self], [EidError]> [EidError] if the crypto provider doesn't support the [DEFAULT_CIPHERSUITE].
+    pub fn with_storage(storage: impl EidMlsStorage + 'static) -> Result<Self, EidError> {
+        Self::new(DEFAULT_CIPHERSUITE, storage)
+    }
+
+    /// Use `config` for all EIDs that are created or joined with this backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `config`: The [EidMlsConfig]
+    ///
+    /// returns: [Result]<[Self], [EidError]> [EidError] if the configuration is invalid.
+    pub fn with_config(mut self, config: EidMlsConfig) -> Result<Self, EidError> {
+        config.validate()?;
+        self.config = config;
+        Ok(self)
+    }
+
+    /// Get the [EidMlsConfig] this backend uses.
+    pub fn config(&self) -> &EidMlsConfig {
+        &self.config
+    }
+
+    /// Get the [Ciphersuite] this backend uses.
+    pub fn ciphersuite(&self) -> Ciphersuite {
+        self.ciphersuite
+    }
+
+    /// Get all [Ciphersuite]s that are supported by the crypto provider.
+    pub fn supported_ciphersuites() -> Vec<Ciphersuite> {
+        RustCrypto::default().supported_ciphersuites()
     }
 }
 
 impl EidBackend for EidMlsBackend {
     #[cfg(feature = "test")]
     type ClientProvider = EidMlsClient;
+
+    /// The new backend uses the same [Ciphersuite] and [EidMlsConfig] and keeps its key material in memory.
+    #[cfg(feature = "test")]
+    fn fresh(&self) -> Self {
+        Self::with_ciphersuite(self.ciphersuite())
+            .and_then(|backend| backend.with_config(self.config.clone()))
+            .expect("Ciphersuite and configuration were valid when the backend was created")
+    }
+
+    type SigningKeyProvider = SignatureKeyPair;
+
+    /// Uses the hash function of the backend's [Ciphersuite].
+    fn hash(&self, data: &[u8]) -> Result<Vec<u8>, EidError> {
+        self.mls_backend
+            .crypto()
+            .hash(self.ciphersuite().hash_algorithm(), data)
+            .map_err(|e| EidError::CryptoError(e.to_string()))
+    }
+
+    /// Generates a key for the signature scheme of the backend's [Ciphersuite].
+    fn generate_signing_key(&self) -> Result<SignatureKeyPair, EidError> {
+        SignatureKeyPair::new(self.ciphersuite().signature_algorithm())
+            .map_err(|e| EidError::CryptoError(e.to_string()))
+    }
+
+    fn public_key(&self, key: &SignatureKeyPair) -> Vec<u8> {
+        key.public().to_vec()
+    }
+
+    fn sign(&self, key: &SignatureKeyPair, data: &[u8]) -> Result<Vec<u8>, EidError> {
+        key.sign(data)
+            .map_err(|e| EidError::CryptoError(format!("Failed to sign: {:?}", e)))
+    }
+
+    /// Verifies with the signature scheme of the backend's [Ciphersuite].
+    fn verify_signature(&self, public_key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+        self.mls_backend
+            .crypto()
+            .verify_signature(
+                self.ciphersuite().signature_algorithm(),
+                data,
+                public_key,
+                signature,
+            )
+            .is_ok()
+    }
+}
+
+/// # EID MLS Crypto Provider
+/// Implements [OpenMlsCryptoProvider] using [RustCrypto] and an [EidMlsKeyStore].
+#[derive(Default)]
+pub struct EidMlsCryptoProvider {
+    crypto: RustCrypto,
+    key_store: EidMlsKeyStore,
+}
+
+impl OpenMlsCryptoProvider for EidMlsCryptoProvider {
+    type CryptoProvider = RustCrypto;
+    type RandProvider = RustCrypto;
+    type KeyStoreProvider = EidMlsKeyStore;
+
+    fn crypto(&self) -> &Self::CryptoProvider {
+        &self.crypto
+    }
+
+    fn rand(&self) -> &Self::RandProvider {
+        &self.crypto
+    }
+
+    fn key_store(&self) -> &Self::KeyStoreProvider {
+        &self.key_store
+    }
 }
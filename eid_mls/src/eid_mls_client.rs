@@ -1,25 +1,38 @@
 use openmls::prelude::{
     Credential, CredentialType, CredentialWithKey, CryptoConfig, KeyPackage, MlsGroup,
+    MlsGroupConfig,
 };
 use openmls::prelude::{
-    MlsGroupConfig, MlsMessageInBody, SenderRatchetConfiguration, PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
+    GroupId, LeafNodeIndex, MlsMessageInBody, Node, QueuedProposal, Welcome, WelcomeError,
 };
 use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::key_store::OpenMlsKeyStore;
 use openmls_traits::signatures::Signer;
 use openmls_traits::types::{Ciphersuite, SignatureScheme};
 use openmls_traits::OpenMlsCryptoProvider;
+use tls_codec::Serialize;
 
 use eid_traits::client::EidClient;
+use eid_traits::evolvement::{EvolvementSummary, EvolvementVerdict};
 use eid_traits::state::EidState;
-use eid_traits::types::EidError;
+use eid_traits::types::{
+    ChallengeResponse, EidChange, EidError, EidId, InvitationExpiry, MembershipEvent,
+};
 
-use crate::eid_mls_backend::EidMlsBackend;
+use crate::eid_mls_backend::{EidMlsBackend, DEFAULT_CIPHERSUITE};
+use crate::eid_mls_config::EidMlsConfig;
 use crate::eid_mls_evolvement::EidMlsEvolvement;
+use crate::eid_mls_key_store::EidMlsKeyStoreEntry;
 use crate::eid_mls_member::EidMlsMember;
+use crate::eid_mls_proof::EidMlsMembershipProof;
 #[cfg(feature = "test")]
 use crate::eid_mls_transcript::EidMlsTranscript;
-use crate::state::client_state::EidMlsClientState;
+use crate::state::client_state::{EidMlsClientState, EidMlsExportedClientState};
 use crate::state::transcript_state::{EidMlsExportedTranscriptState, EidMlsTranscriptState};
+use crate::state::{
+    cross_sign_payload, cross_signatures_after_commit, invitations_after_commit,
+    staged_commit_change, EidMlsAuthenticatedData, EidMlsSignedState,
+};
 
 /// # EID MLS Client
 /// Implementation of [EidClient] using [openmls]. Uses [EidMlsClientState] as its state.
@@ -33,6 +46,8 @@ impl EidClient for EidMlsClient {
     type MemberProvider = EidMlsMember;
     type TranscriptStateProvider = EidMlsTranscriptState;
     type ExportedTranscriptStateProvider = EidMlsExportedTranscriptState;
+    type ExportedClientStateProvider = EidMlsExportedClientState;
+    type MembershipProofProvider = EidMlsMembershipProof;
     type BackendProvider = EidMlsBackend;
     type KeyProvider = SignatureKeyPair;
 
@@ -40,22 +55,37 @@ impl EidClient for EidMlsClient {
     type TranscriptProvider = EidMlsTranscript;
 
     fn create_eid(
+        eid_id: Option<EidId>,
         initial_member: &Self::MemberProvider,
         key_pair: Self::KeyProvider,
         backend: &Self::BackendProvider,
     ) -> Result<Self, EidError> {
-        let mls_group_config = Self::gen_group_config();
+        if key_pair.signature_scheme() != backend.ciphersuite().signature_algorithm() {
+            return Err(EidError::CreateClientError(
+                "Signature scheme of the key pair doesn't match the backend's ciphersuite".into(),
+            ));
+        }
+        let mls_group_config = backend.config().group_config(backend.ciphersuite());
 
-        let group = MlsGroup::new(
-            &backend.mls_backend,
-            &key_pair,
-            &mls_group_config,
-            initial_member.credential.clone(),
-        )
+        let group = match eid_id {
+            Some(eid_id) => MlsGroup::new_with_group_id(
+                &backend.mls_backend,
+                &key_pair,
+                &mls_group_config,
+                GroupId::from_slice(eid_id.as_slice()),
+                initial_member.credential.clone(),
+            ),
+            None => MlsGroup::new(
+                &backend.mls_backend,
+                &key_pair,
+                &mls_group_config,
+                initial_member.credential.clone(),
+            ),
+        }
         .map_err(|e| EidError::CreateClientError(e.to_string()))?;
 
         Ok(Self {
-            state: EidMlsClientState { group },
+            state: EidMlsClientState::new(group),
             key_pair,
         })
     }
@@ -68,33 +98,7 @@ impl EidClient for EidMlsClient {
     where
         Self: Sized,
     {
-        if let EidMlsEvolvement::IN {
-            message: _,
-            welcome: option_message_in,
-        } = invitation
-        {
-            let message_in = option_message_in.ok_or_else(|| {
-                EidError::InvalidInvitationError("Missing welcome message".into())
-            })?;
-            let message_in_body = message_in.extract();
-            if let MlsMessageInBody::Welcome(welcome) = message_in_body {
-                let mls_group_config = Self::gen_group_config();
-                let mls_group = MlsGroup::new_from_welcome(
-                    &backend.mls_backend,
-                    &mls_group_config,
-                    welcome,
-                    None,
-                )
-                .map_err(|err| EidError::CreateClientError(err.to_string()))?;
-                return Ok(Self {
-                    state: EidMlsClientState { group: mls_group },
-                    key_pair: signature_keypair,
-                });
-            }
-        }
-        Err(EidError::InvalidInvitationError(
-            "Wrong evolvement type".into(),
-        ))
+        Self::join(invitation, None, signature_keypair, backend)
     }
 
     fn add(
@@ -102,19 +106,7 @@ impl EidClient for EidMlsClient {
         member: &Self::MemberProvider,
         backend: &Self::BackendProvider,
     ) -> Result<Self::EvolvementProvider, EidError> {
-        if let Some(key_package) = member.key_package.clone() {
-            let group = &mut self.state.group;
-            let (mls_out, welcome, _group_info) = group
-                .add_members(&backend.mls_backend, &self.key_pair, &[key_package])
-                .map_err(|error| EidError::AddMemberError(error.to_string()))?;
-            let evolvement = EidMlsEvolvement::OUT {
-                message: mls_out,
-                welcome: Some(welcome),
-            };
-            Ok(evolvement)
-        } else {
-            Err(EidError::AddMemberError("No key package provided".into()))
-        }
+        self.add_many(std::slice::from_ref(member), backend)
     }
 
     fn remove(
@@ -125,28 +117,98 @@ impl EidClient for EidMlsClient {
     where
         Self: Sized,
     {
-        let group = &mut self.state.group;
+        self.remove_many(std::slice::from_ref(member), backend)
+    }
+
+    fn revoke_invitation(
+        &mut self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        // The invited member may come from a key package, so we remove the member that is in the group
+        let pending_member = self
+            .get_pending_members()
+            .into_iter()
+            .find(|pending_member| pending_member == member)
+            .ok_or_else(|| {
+                EidError::InvalidMemberError("Member has no pending invitation".into())
+            })?;
+        self.remove(&pending_member, backend)
+    }
+
+    fn add_many(
+        &mut self,
+        members: &[Self::MemberProvider],
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        self.invite(members, None, backend)
+    }
 
-        if let Some(mls_member) = &member.mls_member {
-            let (mls_out, welcome, _group_info) = group
-                .remove_members(&backend.mls_backend, &self.key_pair, &[mls_member.index])
-                .map_err(|error| EidError::RemoveMemberError(error.to_string()))?;
-            let evolvement = EidMlsEvolvement::OUT {
-                message: mls_out,
-                welcome,
-            };
-            Ok(evolvement)
-        } else {
-            Err(EidError::InvalidMemberError(
-                "Member not in MLS Group".into(),
-            ))
+    fn add_with_expiry(
+        &mut self,
+        member: &Self::MemberProvider,
+        expiry: InvitationExpiry,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        self.invite(std::slice::from_ref(member), Some(expiry), backend)
+    }
+
+    fn remove_many(
+        &mut self,
+        members: &[Self::MemberProvider],
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if members.is_empty() {
+            return Err(EidError::RemoveMemberError("No members to remove".into()));
         }
+        let indices = Self::leaf_indices(members)?;
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        let (mls_out, welcome, _group_info) = group
+            .remove_members(&backend.mls_backend, &self.key_pair, &indices)
+            .map_err(|error| EidError::RemoveMemberError(error.to_string()))?;
+        let epoch = group.epoch().as_u64();
+        self.state.pending_authenticated_data = None;
+        // Invited members learn the invitations of pending members from the signed state
+        let signed_state = match &welcome {
+            Some(_) => Some(self.sign_pending_state()?),
+            None => None,
+        };
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome,
+            proposals: vec![],
+            signed_state,
+            eid_id,
+            epoch,
+        };
+        Ok(evolvement)
+    }
+
+    fn replace(
+        &mut self,
+        old_member: &Self::MemberProvider,
+        new_member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        self.remove_and_add(
+            std::slice::from_ref(old_member),
+            std::slice::from_ref(new_member),
+            backend,
+        )
     }
 
     fn update(
         &mut self,
         backend: &Self::BackendProvider,
     ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let eid_id = self.eid_id();
         let group = &mut self.state.group;
         let (mls_out, _, _) = group
             .self_update(&backend.mls_backend, &self.key_pair)
@@ -154,29 +216,320 @@ impl EidClient for EidMlsClient {
         let evolvement = EidMlsEvolvement::OUT {
             message: mls_out,
             welcome: None,
+            proposals: vec![],
+            signed_state: None,
+            eid_id,
+            epoch: group.epoch().as_u64(),
+        };
+        Ok(evolvement)
+    }
+
+    fn leave(
+        &mut self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        let mls_out = group
+            .leave_group(&backend.mls_backend, &self.key_pair)
+            .map_err(|error| EidError::RemoveMemberError(error.to_string()))?;
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: None,
+            proposals: vec![],
+            signed_state: None,
+            eid_id,
+            epoch: group.epoch().as_u64(),
+        };
+        Ok(evolvement)
+    }
+
+    fn propose_add(
+        &mut self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let key_packages = Self::key_packages(std::slice::from_ref(member))?;
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        let mls_out = group
+            .propose_add_member(&backend.mls_backend, &self.key_pair, &key_packages[0])
+            .map_err(|error| EidError::AddMemberError(error.to_string()))?;
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: None,
+            proposals: vec![],
+            signed_state: None,
+            eid_id,
+            epoch: group.epoch().as_u64(),
+        };
+        Ok(evolvement)
+    }
+
+    fn propose_remove(
+        &mut self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let indices = Self::leaf_indices(std::slice::from_ref(member))?;
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        let mls_out = group
+            .propose_remove_member(&backend.mls_backend, &self.key_pair, indices[0])
+            .map_err(|error| EidError::RemoveMemberError(error.to_string()))?;
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: None,
+            proposals: vec![],
+            signed_state: None,
+            eid_id,
+            epoch: group.epoch().as_u64(),
+        };
+        Ok(evolvement)
+    }
+
+    fn propose_update(
+        &mut self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        let mls_out = group
+            .propose_self_update(&backend.mls_backend, &self.key_pair, None)
+            .map_err(|error| EidError::UpdateMemberError(error.to_string()))?;
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: None,
+            proposals: vec![],
+            signed_state: None,
+            eid_id,
+            epoch: group.epoch().as_u64(),
+        };
+        Ok(evolvement)
+    }
+
+    fn commit_pending_proposals(
+        &mut self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        if group.pending_proposals().next().is_none() {
+            return Err(EidError::CommitError("No pending proposals".into()));
+        }
+        let (mls_out, welcome, _group_info) = group
+            .commit_to_pending_proposals(&backend.mls_backend, &self.key_pair)
+            .map_err(|error| EidError::CommitError(error.to_string()))?;
+        let epoch = group.epoch().as_u64();
+        self.state.pending_authenticated_data = None;
+        // Invited members learn the invitations of pending members from the signed state
+        let signed_state = match &welcome {
+            Some(_) => Some(self.sign_pending_state()?),
+            None => None,
+        };
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome,
+            proposals: vec![],
+            signed_state,
+            eid_id,
+            epoch,
         };
         Ok(evolvement)
     }
 
+    fn is_active(&self) -> bool {
+        self.state.group.is_active()
+    }
+
     fn evolve(
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError> {
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
         self.state.apply(evolvement, backend)
     }
 
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict {
+        if !self.is_active() {
+            return EidError::InactiveClientError("Client is inactive".into()).into();
+        }
+        self.state.validate(evolvement, backend)
+    }
+
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError> {
+        self.state.describe(evolvement, backend)
+    }
+
+    fn batch_evolve(
+        &mut self,
+        evolvements: Vec<Self::EvolvementProvider>,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        // Applying an evolvement changes the group and the key store entries it references,
+        // so we snapshot both and restore them on failure
+        let snapshot = self.state.snapshot(backend)?;
+        let mut events = vec![];
+        for evolvement in evolvements {
+            match self.evolve(evolvement, backend) {
+                Ok(evolvement_events) => events.extend(evolvement_events),
+                Err(error) => return Err(self.state.roll_back(snapshot, error, backend)),
+            }
+        }
+        Ok(events)
+    }
+
     fn cross_sign_membership(
         &mut self,
         backend: &Self::BackendProvider,
     ) -> Result<Self::EvolvementProvider, EidError> {
-        self.update(backend)
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        // Sign the EID identifier and our own credential and send the signature along with an update
+        let own_index = self.state.group.own_leaf_index();
+        let own_member = self
+            .state
+            .group
+            .members()
+            .find(|member| member.index == own_index)
+            .map(EidMlsMember::from_existing)
+            .ok_or_else(|| EidError::InvalidMemberError("Own leaf not in MLS Group".into()))?;
+        let payload = cross_sign_payload(self.eid_id().as_slice(), &own_member.credential)?;
+        let signature = self
+            .key_pair
+            .sign(&payload)
+            .map_err(|e| EidError::UpdateMemberError(e.to_string()))?;
+        let authenticated_data = EidMlsAuthenticatedData::CrossSignature(signature);
+        let aad = authenticated_data.to_aad()?;
+
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        group.set_aad(&aad);
+        let result = group.self_update(&backend.mls_backend, &self.key_pair);
+        group.set_aad(&[]);
+        let (mls_out, _, _) =
+            result.map_err(|error| EidError::UpdateMemberError(error.to_string()))?;
+        self.state.pending_authenticated_data = Some(authenticated_data);
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: None,
+            proposals: vec![],
+            signed_state: None,
+            eid_id,
+            epoch: group.epoch().as_u64(),
+        };
+        Ok(evolvement)
+    }
+
+    fn pending_change(&self) -> Option<EidChange<EidMlsMember>> {
+        let group = &self.state.group;
+        let staged_commit = group.pending_commit()?;
+        let members: Vec<_> = group.members().collect();
+        Some(staged_commit_change(staged_commit, &members))
+    }
+
+    fn resolve_conflict(
+        &mut self,
+        evolvement: Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Option<EidChange<EidMlsMember>>, EidError> {
+        let change = self.pending_change();
+        // The pending commit can't be staged again, so it is restored from a snapshot if the evolvement fails
+        let snapshot = self.state.snapshot(backend)?;
+        self.state.group.clear_pending_commit();
+        self.state.pending_authenticated_data = None;
+        if let Err(error) = self.evolve(evolvement, backend) {
+            return Err(self.state.roll_back(snapshot, error, backend));
+        }
+        Ok(change)
+    }
+
+    fn recreate(
+        &mut self,
+        change: &EidChange<EidMlsMember>,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let members: Vec<_> = self
+            .state
+            .group
+            .members()
+            .map(EidMlsMember::from_existing)
+            .collect();
+        let added: Vec<_> = change
+            .added
+            .iter()
+            .filter(|&member| !members.contains(member))
+            .cloned()
+            .collect();
+        // Take the removed members from the current group, since their leaf index may have changed
+        let removed: Vec<_> = members
+            .into_iter()
+            .filter(|member| change.removed.contains(member))
+            .collect();
+
+        match (added.is_empty(), removed.is_empty()) {
+            // A self update of a member that hasn't cross-signed yet was its cross sign
+            (true, true) if change.self_update && !self.has_cross_signed() => {
+                self.cross_sign_membership(backend)
+            }
+            (true, true) if change.self_update => self.update(backend),
+            (true, true) => Err(EidError::CommitError("Nothing to re-create".into())),
+            (false, true) => self.add_many(&added, backend),
+            (true, false) => self.remove_many(&removed, backend),
+            (false, false) => self.remove_and_add(&removed, &added, backend),
+        }
     }
 
     fn get_members(&self) -> Vec<Self::MemberProvider> {
         self.state.get_members()
     }
 
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider> {
+        self.state.get_pending_members()
+    }
+
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider> {
+        self.state.get_expired_members(now)
+    }
+
+    fn epoch(&self) -> u64 {
+        self.state.epoch()
+    }
+
+    fn eid_id(&self) -> EidId {
+        self.state.eid_id()
+    }
+
     fn export_transcript_state(
         &self,
         backend: &Self::BackendProvider,
@@ -187,10 +540,115 @@ impl EidClient for EidMlsClient {
             .export_group_info(&backend.mls_backend, &self.key_pair, false)
             .map_err(|e| EidError::ExportTranscriptStateError(e.to_string()))?;
         let nodes = self.state.group.export_ratchet_tree();
+        let signed_state = EidMlsSignedState::sign(
+            self.eid_id().as_slice(),
+            self.epoch(),
+            self.state.cross_signatures.clone(),
+            self.state.invitations.clone(),
+            &self.key_pair,
+        )?;
 
         Ok(EidMlsExportedTranscriptState::OUT {
             group_info: mls_out,
             nodes,
+            signed_state,
+        })
+    }
+
+    fn prove_membership(
+        &self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MembershipProofProvider, EidError> {
+        if !self.has_cross_signed() {
+            return Err(EidError::InactiveClientError(
+                "Client is not a cross-signed member".into(),
+            ));
+        }
+        let group_info = self
+            .state
+            .group
+            .export_group_info(&backend.mls_backend, &self.key_pair, false)
+            .map_err(|e| EidError::InvalidMembershipProofError(e.to_string()))?
+            .tls_serialize_detached()
+            .map_err(|e| EidError::SerializationError(e.to_string()))?;
+
+        Ok(EidMlsMembershipProof {
+            eid_id: self.eid_id(),
+            epoch: self.epoch(),
+            group_info,
+            nodes: self.state.group.export_ratchet_tree(),
+            cross_signatures: self.state.cross_signatures.clone(),
+        })
+    }
+
+    fn sign_challenge(
+        &self,
+        nonce: &[u8],
+        _backend: &Self::BackendProvider,
+    ) -> Result<ChallengeResponse, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let content = ChallengeResponse::signed_content(&self.eid_id(), nonce)?;
+        let signature = self
+            .key_pair
+            .sign(&content)
+            .map_err(|e| EidError::InvalidChallengeResponseError(e.to_string()))?;
+        Ok(ChallengeResponse {
+            signature_key: self.key_pair.public().to_vec(),
+            signature,
+        })
+    }
+
+    fn export_client_state(
+        &self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::ExportedClientStateProvider, EidError> {
+        let group = serde_json::to_vec(&self.state.group)
+            .map_err(|e| EidError::ExportClientStateError(e.to_string()))?;
+        let key_pair = serde_json::to_vec(&self.key_pair)
+            .map_err(|e| EidError::ExportClientStateError(e.to_string()))?;
+        // Clients may share a backend, so only the entries of this client are exported
+        let key_store = backend
+            .mls_backend
+            .key_store()
+            .export_entries(&self.state.key_store_keys()?)
+            .map_err(|e| EidError::ExportClientStateError(e.to_string()))?;
+
+        Ok(EidMlsExportedClientState {
+            group,
+            cross_signatures: self.state.cross_signatures.clone(),
+            invitations: self.state.invitations.clone(),
+            key_pair,
+            key_store,
+        })
+    }
+
+    fn restore_client_state(
+        state: Self::ExportedClientStateProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self, EidError> {
+        let group: MlsGroup = serde_json::from_slice(&state.group)
+            .map_err(|e| EidError::RestoreClientStateError(e.to_string()))?;
+        let key_pair: SignatureKeyPair = serde_json::from_slice(&state.key_pair)
+            .map_err(|e| EidError::RestoreClientStateError(e.to_string()))?;
+        backend
+            .mls_backend
+            .key_store()
+            .import_entries(state.key_store)
+            .map_err(|e| EidError::RestoreClientStateError(e.to_string()))?;
+        key_pair
+            .store(backend.mls_backend.key_store())
+            .map_err(|e| EidError::RestoreClientStateError(e.to_string()))?;
+
+        Ok(Self {
+            state: EidMlsClientState {
+                group,
+                cross_signatures: state.cross_signatures,
+                invitations: state.invitations,
+                pending_authenticated_data: None,
+            },
+            key_pair,
         })
     }
 
@@ -199,18 +657,12 @@ impl EidClient for EidMlsClient {
         id: Vec<u8>,
         backend: &Self::BackendProvider,
     ) -> (Self::MemberProvider, Self::KeyProvider) {
-        let ciphersuite = backend.ciphersuite;
-        let (cred_with_key, keypair) = Self::create_store_credential(
-            id,
-            CredentialType::Basic,
-            ciphersuite.signature_algorithm(),
-            backend,
-        )
-        .expect("Failed to create credential");
+        let (cred_with_key, keypair) =
+            Self::generate_credential(id, CredentialType::Basic, backend)
+                .expect("Failed to create credential");
 
-        let key_package =
-            Self::create_store_key_package(ciphersuite, cred_with_key.clone(), backend, &keypair)
-                .expect("Failed to create key package");
+        let key_package = Self::generate_key_package(cred_with_key.clone(), backend, &keypair)
+            .expect("Failed to create key package");
 
         (
             EidMlsMember {
@@ -225,24 +677,322 @@ impl EidClient for EidMlsClient {
     #[cfg(feature = "test")]
     fn generate_initial_client(id: Vec<u8>, backend: &Self::BackendProvider) -> Self {
         let (member, keypair) = Self::generate_member(id, backend);
-        Self::create_eid(&member, keypair, backend).expect("Could not create EID")
+        Self::create_eid(None, &member, keypair, backend).expect("Could not create EID")
     }
 }
 
 impl EidMlsClient {
-    pub fn gen_group_config() -> MlsGroupConfig {
-        MlsGroupConfig::builder()
-            .sender_ratchet_configuration(SenderRatchetConfiguration::new(10, 2000))
-            .use_ratchet_tree_extension(true)
-            .wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
-            .build()
+    /// Create an [EidMlsClient] from an invitation whose ratchet tree is delivered out of band.
+    /// This is needed if the backend's [EidMlsConfig](crate::eid_mls_config::EidMlsConfig) doesn't use the ratchet tree extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `invitation`: The [EidMlsEvolvement] that was created when the member was added to the EID
+    /// * `ratchet_tree`: The ratchet tree of the EID after the member was added (see [Self::export_ratchet_tree])
+    /// * `signature_keypair`: The invited member's key material
+    /// * `backend`: The [EidMlsBackend]
+    ///
+    /// returns: [Result]<[Self], [EidError]>
+    pub fn create_from_invitation_with_ratchet_tree(
+        invitation: EidMlsEvolvement,
+        ratchet_tree: Vec<Option<Node>>,
+        signature_keypair: SignatureKeyPair,
+        backend: &EidMlsBackend,
+    ) -> Result<Self, EidError> {
+        Self::join(invitation, Some(ratchet_tree), signature_keypair, backend)
+    }
+
+    /// Export the ratchet tree of the EID, e.g. to deliver it to an invited member out of band.
+    pub fn export_ratchet_tree(&self) -> Vec<Option<Node>> {
+        self.state.group.export_ratchet_tree()
+    }
+
+    /// Get the proposals that were received or sent and wait to be committed.
+    pub fn pending_proposals(&self) -> impl Iterator<Item = &QueuedProposal> {
+        self.state.group.pending_proposals()
+    }
+
+    /// True if the client has cross-signed its membership.
+    fn has_cross_signed(&self) -> bool {
+        self.state.cross_signatures.iter().any(|cross_signature| {
+            cross_signature.signature_key.as_slice() == self.key_pair.public()
+        })
+    }
+
+    fn key_packages(members: &[EidMlsMember]) -> Result<Vec<KeyPackage>, EidError> {
+        members
+            .iter()
+            .map(|member| {
+                member
+                    .key_package
+                    .clone()
+                    .ok_or_else(|| EidError::AddMemberError("No key package provided".into()))
+            })
+            .collect()
+    }
+
+    /// Create a commit that adds `members` whose invitations expire with `expiry`.
+    /// The expiry is sent as authenticated data of the commit, so that other clients and transcripts learn it.
+    fn invite(
+        &mut self,
+        members: &[EidMlsMember],
+        expiry: Option<InvitationExpiry>,
+        backend: &EidMlsBackend,
+    ) -> Result<EidMlsEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        if members.is_empty() {
+            return Err(EidError::AddMemberError("No members to add".into()));
+        }
+        let key_packages = Self::key_packages(members)?;
+        let authenticated_data = expiry.map(EidMlsAuthenticatedData::InvitationExpiry);
+        let aad = match &authenticated_data {
+            Some(authenticated_data) => authenticated_data.to_aad()?,
+            None => vec![],
+        };
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        group.set_aad(&aad);
+        let result = group.add_members(&backend.mls_backend, &self.key_pair, &key_packages);
+        group.set_aad(&[]);
+        let (mls_out, welcome, _group_info) =
+            result.map_err(|error| EidError::AddMemberError(error.to_string()))?;
+        self.state.pending_authenticated_data = authenticated_data;
+        let epoch = group.epoch().as_u64();
+        // Invited members learn the invitations of pending members from the signed state
+        let signed_state = Some(self.sign_pending_state()?);
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: Some(welcome),
+            proposals: vec![],
+            signed_state,
+            eid_id,
+            epoch,
+        };
+        Ok(evolvement)
+    }
+
+    /// Create a single commit that removes `old_members` and adds `new_members`.
+    /// The removals are proposed first and committed together with the adds.
+    fn remove_and_add(
+        &mut self,
+        old_members: &[EidMlsMember],
+        new_members: &[EidMlsMember],
+        backend: &EidMlsBackend,
+    ) -> Result<EidMlsEvolvement, EidError> {
+        if !self.is_active() {
+            return Err(EidError::InactiveClientError("Client is inactive".into()));
+        }
+        let indices = Self::leaf_indices(old_members)?;
+        let key_packages = Self::key_packages(new_members)?;
+        let eid_id = self.eid_id();
+        let group = &mut self.state.group;
+        // The commit would include all proposals in the store, but it may only contain the requested changes
+        if group.pending_proposals().next().is_some() {
+            return Err(EidError::CommitError(
+                "There are pending proposals, commit them first".into(),
+            ));
+        }
+
+        // The remove proposals are stored in the group's proposal store and committed together with the add.
+        let mut remove_proposals = vec![];
+        for index in indices {
+            let remove_proposal = group
+                .propose_remove_member(&backend.mls_backend, &self.key_pair, index)
+                .map_err(|error| {
+                    group.clear_pending_proposals();
+                    EidError::RemoveMemberError(error.to_string())
+                })?;
+            remove_proposals.push(remove_proposal);
+        }
+        let (mls_out, welcome, _group_info) = group
+            .add_members(&backend.mls_backend, &self.key_pair, &key_packages)
+            .map_err(|error| {
+                group.clear_pending_proposals();
+                EidError::AddMemberError(error.to_string())
+            })?;
+        let epoch = group.epoch().as_u64();
+        self.state.pending_authenticated_data = None;
+        // Invited members learn the invitations of pending members from the signed state
+        let signed_state = Some(self.sign_pending_state()?);
+        let evolvement = EidMlsEvolvement::OUT {
+            message: mls_out,
+            welcome: Some(welcome),
+            proposals: remove_proposals,
+            signed_state,
+            eid_id,
+            epoch,
+        };
+        Ok(evolvement)
+    }
+
+    fn leaf_indices(members: &[EidMlsMember]) -> Result<Vec<LeafNodeIndex>, EidError> {
+        members
+            .iter()
+            .map(|member| {
+                member
+                    .mls_member
+                    .as_ref()
+                    .map(|mls_member| mls_member.index)
+                    .ok_or_else(|| EidError::InvalidMemberError("Member not in MLS Group".into()))
+            })
+            .collect()
+    }
+
+    fn join(
+        invitation: EidMlsEvolvement,
+        ratchet_tree: Option<Vec<Option<Node>>>,
+        signature_keypair: SignatureKeyPair,
+        backend: &EidMlsBackend,
+    ) -> Result<Self, EidError> {
+        let config = backend.config();
+        if let EidMlsEvolvement::IN {
+            welcome: option_message_in,
+            signed_state,
+            ..
+        } = invitation
+        {
+            let message_in = option_message_in.ok_or_else(|| {
+                EidError::InvalidInvitationError("Missing welcome message".into())
+            })?;
+            let message_in_body = message_in.extract();
+            if let MlsMessageInBody::Welcome(welcome) = message_in_body {
+                let mls_group_config = config.group_config(backend.ciphersuite());
+                // Joining consumes the key package even if it fails, so we restore it on failure
+                let key_package_entries = Self::key_package_entries(&welcome, backend)?;
+                let mls_group = match MlsGroup::new_from_welcome(
+                    &backend.mls_backend,
+                    &mls_group_config,
+                    welcome,
+                    ratchet_tree,
+                ) {
+                    Ok(mls_group) => mls_group,
+                    Err(error) => {
+                        backend
+                            .mls_backend
+                            .key_store()
+                            .import_entries(key_package_entries)
+                            .map_err(|e| EidError::CreateClientError(e.to_string()))?;
+                        return Err(match error {
+                            WelcomeError::MissingRatchetTree => EidError::InvalidInvitationError(
+                                "The invitation doesn't carry the ratchet tree, it has to be delivered out of band"
+                                    .into(),
+                            ),
+                            error => EidError::CreateClientError(error.to_string()),
+                        });
+                    }
+                };
+                config.verify_joined_group(&mls_group, backend.ciphersuite())?;
+                let mut state = EidMlsClientState::new(mls_group);
+                // Cross signatures and invitations aren't covered by the welcome, so a member has to have signed them
+                let members: Vec<_> = state.group.members().collect();
+                let signed_state = signed_state.ok_or_else(|| {
+                    EidError::InvalidInvitationError("Missing signed state".into())
+                })?;
+                signed_state.verify(
+                    state.eid_id().as_slice(),
+                    state.epoch(),
+                    &members,
+                    state.signature_scheme(),
+                    backend,
+                )?;
+                state.cross_signatures = signed_state.cross_signatures;
+                state.invitations = signed_state.invitations;
+                return Ok(Self {
+                    state,
+                    key_pair: signature_keypair,
+                });
+            }
+        }
+        Err(EidError::InvalidInvitationError(
+            "Wrong evolvement type".into(),
+        ))
+    }
+
+    /// Get the key store entries of the key package a welcome is encrypted for,
+    /// i.e. the key package, the private key of its init key and the key pair of its leaf's encryption key.
+    fn key_package_entries(
+        welcome: &Welcome,
+        backend: &EidMlsBackend,
+    ) -> Result<Vec<EidMlsKeyStoreEntry>, EidError> {
+        let key_store = backend.mls_backend.key_store();
+        let mut keys = vec![];
+        for secrets in welcome.secrets() {
+            let hash_ref = secrets.new_member().as_slice().to_vec();
+            if let Some(key_package) = key_store.read::<KeyPackage>(&hash_ref) {
+                keys.push(key_package.hpke_init_key().as_slice().to_vec());
+                keys.push(key_package.leaf_node().encryption_key().as_slice().to_vec());
+                keys.push(hash_ref);
+            }
+        }
+        key_store
+            .export_entries(&keys)
+            .map_err(|e| EidError::CreateClientError(e.to_string()))
+    }
+
+    /// Sign the cross signatures and invitations the EID has once the pending commit is merged (see [EidMlsSignedState]).
+    /// Commits that invite members don't cross-sign, so the cross signatures only lose the removed members.
+    fn sign_pending_state(&self) -> Result<EidMlsSignedState, EidError> {
+        let change = self
+            .pending_change()
+            .ok_or_else(|| EidError::CommitError("No pending commit".into()))?;
+        let expiry = self
+            .state
+            .pending_authenticated_data
+            .as_ref()
+            .and_then(EidMlsAuthenticatedData::invitation_expiry);
+        let invitations = invitations_after_commit(
+            &self.state.invitations,
+            self.get_pending_members(),
+            &change,
+            expiry,
+        );
+        let cross_signatures =
+            cross_signatures_after_commit(&self.state.cross_signatures, &change, None);
+        EidMlsSignedState::sign(
+            self.eid_id().as_slice(),
+            self.epoch() + 1,
+            cross_signatures,
+            invitations,
+            &self.key_pair,
+        )
     }
 
+    /// Create a credential and a signature key pair using the signature scheme of the backend's ciphersuite.
+    /// The key pair is stored in the backend's key store.
+    pub fn generate_credential(
+        identity: Vec<u8>,
+        credential_type: CredentialType,
+        backend: &EidMlsBackend,
+    ) -> Result<(CredentialWithKey, SignatureKeyPair), EidError> {
+        Self::store_credential(
+            identity,
+            credential_type,
+            backend.ciphersuite().signature_algorithm(),
+            backend,
+        )
+    }
+
+    /// Create a credential and a signature key pair using `signature_algorithm`.
+    /// The key pair is stored in the backend's key store.
+    #[deprecated(
+        note = "Use EidMlsClient::generate_credential, which uses the signature scheme of the backend's ciphersuite"
+    )]
     pub fn create_store_credential(
         identity: Vec<u8>,
         credential_type: CredentialType,
         signature_algorithm: SignatureScheme,
         backend: &EidMlsBackend,
+    ) -> Result<(CredentialWithKey, SignatureKeyPair), EidError> {
+        Self::store_credential(identity, credential_type, signature_algorithm, backend)
+    }
+
+    fn store_credential(
+        identity: Vec<u8>,
+        credential_type: CredentialType,
+        signature_algorithm: SignatureScheme,
+        backend: &EidMlsBackend,
     ) -> Result<(CredentialWithKey, SignatureKeyPair), EidError> {
         let credential = Credential::new(identity, credential_type)
             .map_err(|e| EidError::CreateCredentialError(e.to_string()))?;
@@ -261,11 +1011,56 @@ impl EidMlsClient {
         ))
     }
 
+    /// Read a signature key pair that was stored with [Self::generate_credential] from the backend's key store,
+    /// e.g. after a restart with a persistent [EidMlsStorage](crate::eid_mls_storage::EidMlsStorage).
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_key`: The public signature key of the credential
+    /// * `backend`: The [EidMlsBackend]
+    ///
+    /// returns: [Result]<[SignatureKeyPair], [EidError]> [EidError::CreateCredentialError] if no key pair is stored for the key.
+    pub fn read_stored_key_pair(
+        signature_key: &[u8],
+        backend: &EidMlsBackend,
+    ) -> Result<SignatureKeyPair, EidError> {
+        SignatureKeyPair::read(
+            backend.mls_backend.key_store(),
+            signature_key,
+            backend.ciphersuite().signature_algorithm(),
+        )
+        .ok_or_else(|| {
+            EidError::CreateCredentialError("No key pair stored for the signature key".into())
+        })
+    }
+
+    /// Create a key package for the backend's ciphersuite. Its private key material is stored in the backend's key store.
+    pub fn generate_key_package(
+        credential_with_key: CredentialWithKey,
+        backend: &EidMlsBackend,
+        signer: &impl Signer,
+    ) -> Result<KeyPackage, EidError> {
+        Self::store_key_package(backend.ciphersuite(), credential_with_key, backend, signer)
+    }
+
+    /// Create a key package for `ciphersuite`. Its private key material is stored in the backend's key store.
+    #[deprecated(
+        note = "Use EidMlsClient::generate_key_package, which uses the backend's ciphersuite"
+    )]
     pub fn create_store_key_package(
         ciphersuite: Ciphersuite,
         credential_with_key: CredentialWithKey,
         backend: &EidMlsBackend,
         signer: &impl Signer,
+    ) -> Result<KeyPackage, EidError> {
+        Self::store_key_package(ciphersuite, credential_with_key, backend, signer)
+    }
+
+    fn store_key_package(
+        ciphersuite: Ciphersuite,
+        credential_with_key: CredentialWithKey,
+        backend: &EidMlsBackend,
+        signer: &impl Signer,
     ) -> Result<KeyPackage, EidError> {
         let key_package = KeyPackage::builder()
             //.key_package_extensions(extensions)
@@ -278,4 +1073,10 @@ impl EidMlsClient {
             .map_err(|e| EidError::CreateCredentialError(e.to_string()))?;
         Ok(key_package)
     }
+
+    /// Create the [MlsGroupConfig] of the default [EidMlsConfig] and the [DEFAULT_CIPHERSUITE].
+    #[deprecated(note = "Configure the backend with EidMlsBackend::with_config instead")]
+    pub fn gen_group_config() -> MlsGroupConfig {
+        EidMlsConfig::default().group_config(DEFAULT_CIPHERSUITE)
+    }
 }
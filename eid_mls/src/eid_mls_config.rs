@@ -0,0 +1,118 @@
+use openmls::prelude::{
+    CryptoConfig, MlsGroup, MlsGroupConfig, RequiredCapabilitiesExtension,
+    SenderRatchetConfiguration, PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
+};
+use openmls_traits::types::Ciphersuite;
+
+use eid_traits::types::EidError;
+
+/// # EID MLS Config
+/// Configuration of the [MlsGroup]s that are used for EIDs, held by the [EidMlsBackend](crate::eid_mls_backend::EidMlsBackend).
+/// All clients of an EID should use the same configuration.
+/// Only the ciphersuite and the required capabilities are part of the group context,
+/// so only they are verified when a client joins an EID.
+/// The other settings are local to a client and can't be observed by the members that join:
+/// padding, past epochs and sender ratchet only affect how a client processes messages,
+/// and the ratchet tree extension only affects the invitations a client creates.
+///
+/// The wire format policy can't be configured. Evolvements are always sent as plaintext,
+/// since the transcript can't decrypt them otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EidMlsConfig {
+    /// Size the MLS messages are padded to.
+    pub padding_size: usize,
+    /// Number of past epochs for which message secrets are kept.
+    pub max_past_epochs: usize,
+    /// Number of out of order generations that can be decrypted.
+    pub out_of_order_tolerance: u32,
+    /// Maximum number of generations a message may be ahead.
+    pub maximum_forward_distance: u32,
+    /// Capabilities every member of the EID is required to support.
+    pub required_capabilities: RequiredCapabilitiesExtension,
+    /// If true, the ratchet tree is included in invitations.
+    /// If false, it has to be delivered out of band (see [EidMlsClient::create_from_invitation_with_ratchet_tree](crate::eid_mls_client::EidMlsClient::create_from_invitation_with_ratchet_tree)).
+    pub use_ratchet_tree_extension: bool,
+}
+
+impl Default for EidMlsConfig {
+    fn default() -> Self {
+        Self {
+            padding_size: 0,
+            max_past_epochs: 0,
+            out_of_order_tolerance: 10,
+            maximum_forward_distance: 2000,
+            required_capabilities: RequiredCapabilitiesExtension::default(),
+            use_ratchet_tree_extension: true,
+        }
+    }
+}
+
+impl EidMlsConfig {
+    /// Check that the configuration is consistent.
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError] if the configuration is invalid.
+    pub fn validate(&self) -> Result<(), EidError> {
+        if self.maximum_forward_distance == 0 {
+            return Err(EidError::InvalidConfigError(
+                "Maximum forward distance must be greater than 0".into(),
+            ));
+        }
+        if self.out_of_order_tolerance > self.maximum_forward_distance {
+            return Err(EidError::InvalidConfigError(
+                "Out of order tolerance must not exceed the maximum forward distance".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Create the [MlsGroupConfig] for an EID using `ciphersuite`.
+    pub(crate) fn group_config(&self, ciphersuite: Ciphersuite) -> MlsGroupConfig {
+        MlsGroupConfig::builder()
+            .crypto_config(CryptoConfig::with_default_version(ciphersuite))
+            .padding_size(self.padding_size)
+            .max_past_epochs(self.max_past_epochs)
+            .sender_ratchet_configuration(SenderRatchetConfiguration::new(
+                self.out_of_order_tolerance,
+                self.maximum_forward_distance,
+            ))
+            .required_capabilities(self.required_capabilities.clone())
+            .use_ratchet_tree_extension(self.use_ratchet_tree_extension)
+            .wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+            .build()
+    }
+
+    /// Check that a group that was joined agrees with this configuration and `ciphersuite`.
+    /// Only the ciphersuite and the required capabilities can be observed in the group context,
+    /// mismatches of the local settings aren't detected.
+    ///
+    /// # Arguments
+    ///
+    /// * `group`: The joined [MlsGroup]
+    /// * `ciphersuite`: The expected [Ciphersuite]
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError] if the group was created with a different configuration.
+    pub(crate) fn verify_joined_group(
+        &self,
+        group: &MlsGroup,
+        ciphersuite: Ciphersuite,
+    ) -> Result<(), EidError> {
+        if group.ciphersuite() != ciphersuite {
+            return Err(EidError::InvalidInvitationError(format!(
+                "EID uses ciphersuite {:?}, expected {:?}",
+                group.ciphersuite(),
+                ciphersuite
+            )));
+        }
+        let required_capabilities = group
+            .export_group_context()
+            .required_capabilities()
+            .cloned()
+            .unwrap_or_default();
+        if required_capabilities != self.required_capabilities {
+            return Err(EidError::InvalidInvitationError(
+                "EID requires different capabilities than configured".into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -1,28 +1,59 @@
 use std::io::{Read, Write};
 
-use openmls::prelude::{MlsMessageIn, MlsMessageOut};
+use openmls::prelude::{MlsMessageIn, MlsMessageInBody, MlsMessageOut, ProtocolMessage};
 use tls_codec::{Deserialize, Error as TlsError, Serialize, Size};
 
 use eid_traits::evolvement::Evolvement;
+use eid_traits::types::{EidError, EidId};
+
+use crate::state::EidMlsSignedState;
 
 /// # EidMlsEvolvement
 /// Implementation of [Evolvement] using [openmls].
 /// To keep the API as simple as possible, [EidMlsEvolvement] doesn't introduce extra types for in- and outbound [Evolvement]s.
-#[derive(Debug, Clone)]
+///
+/// Besides the commit `message` and an optional `welcome`, an evolvement carries the `proposals` the commit
+/// refers to, e.g. when replacing a member. They are processed before the commit.
+///
+/// An evolvement that may invite members also carries the `signed_state` of the EID after the commit,
+/// which holds the cross signatures of the members and the invitations of pending members (see [EidMlsSignedState]).
+/// Invited members require it, existing members verify it against their own view.
+///
+/// The `eid_id` is the id of the group and the `epoch` is the epoch of the group the `message` was created in.
+/// They aren't serialized separately, but read from the `message` when deserializing.
+/// An inbound evolvement remembers the bytes it was deserialized from in `serialized`,
+/// e.g. to hash it when it is appended to the log of a transcript (see [Self::to_bytes]).
+#[derive(Debug, Clone, PartialEq)]
 pub enum EidMlsEvolvement {
     OUT {
         message: MlsMessageOut,
         welcome: Option<MlsMessageOut>,
+        proposals: Vec<MlsMessageOut>,
+        signed_state: Option<EidMlsSignedState>,
+        eid_id: EidId,
+        epoch: u64,
     },
     IN {
         message: MlsMessageIn,
         welcome: Option<MlsMessageIn>,
+        proposals: Vec<MlsMessageIn>,
+        signed_state: Option<EidMlsSignedState>,
+        eid_id: EidId,
+        epoch: u64,
+        serialized: Vec<u8>,
     },
 }
 
 impl Serialize for EidMlsEvolvement {
     fn tls_serialize<W: Write>(&self, writer: &mut W) -> Result<usize, TlsError> {
-        if let Self::OUT { message, welcome } = self {
+        if let Self::OUT {
+            message,
+            welcome,
+            proposals,
+            signed_state,
+            ..
+        } = self
+        {
             let mut bytes_written = 0;
             let msg_ser = message.tls_serialize_detached()?;
             bytes_written += writer.write(msg_ser.as_slice())?;
@@ -30,6 +61,12 @@ impl Serialize for EidMlsEvolvement {
             let welcome_ser = welcome.tls_serialize_detached()?;
             bytes_written += writer.write(welcome_ser.as_slice())?;
 
+            let proposals_ser = proposals.tls_serialize_detached()?;
+            bytes_written += writer.write(proposals_ser.as_slice())?;
+
+            let signed_state_ser = signed_state.tls_serialize_detached()?;
+            bytes_written += writer.write(signed_state_ser.as_slice())?;
+
             Ok(bytes_written)
         } else {
             Err(TlsError::EncodingError(String::from(
@@ -42,21 +79,37 @@ impl Serialize for EidMlsEvolvement {
 impl Size for EidMlsEvolvement {
     fn tls_serialized_len(&self) -> usize {
         match self {
-            Self::OUT { message, welcome } => {
+            Self::OUT {
+                message,
+                welcome,
+                proposals,
+                signed_state,
+                ..
+            } => {
                 let len = message.tls_serialized_len();
                 let welcome_len = match welcome {
                     None => 0,
                     Some(msg) => msg.tls_serialized_len(),
                 };
                 len + welcome_len
+                    + proposals.tls_serialized_len()
+                    + signed_state.tls_serialized_len()
             }
-            Self::IN { message, welcome } => {
+            Self::IN {
+                message,
+                welcome,
+                proposals,
+                signed_state,
+                ..
+            } => {
                 let len = message.tls_serialized_len();
                 let welcome_len = match welcome {
                     None => 0,
                     Some(msg) => msg.tls_serialized_len(),
                 };
                 len + welcome_len
+                    + proposals.tls_serialized_len()
+                    + signed_state.tls_serialized_len()
             }
         }
     }
@@ -67,10 +120,81 @@ impl Deserialize for EidMlsEvolvement {
     where
         Self: Sized,
     {
-        let message = MlsMessageIn::tls_deserialize(bytes)?;
-        let welcome = Option::<MlsMessageIn>::tls_deserialize(bytes)?;
-        Ok(Self::IN { message, welcome })
+        let mut reader = RecordingReader {
+            reader: bytes,
+            bytes: vec![],
+        };
+        let message = MlsMessageIn::tls_deserialize(&mut reader)?;
+        let welcome = Option::<MlsMessageIn>::tls_deserialize(&mut reader)?;
+        let proposals = Vec::<MlsMessageIn>::tls_deserialize(&mut reader)?;
+        let signed_state = Option::<EidMlsSignedState>::tls_deserialize(&mut reader)?;
+        let serialized = reader.bytes;
+        let (eid_id, epoch) = match message.clone().extract() {
+            MlsMessageInBody::PublicMessage(public_message) => {
+                let protocol_message = ProtocolMessage::PublicMessage(public_message);
+                (
+                    EidId::new(protocol_message.group_id().as_slice().to_vec()),
+                    protocol_message.epoch().as_u64(),
+                )
+            }
+            body => {
+                return Err(TlsError::DecodingError(format!(
+                    "Expected PublicMessage, got {:?}",
+                    body
+                )))
+            }
+        };
+        Ok(Self::IN {
+            message,
+            welcome,
+            proposals,
+            signed_state,
+            eid_id,
+            epoch,
+            serialized,
+        })
+    }
+}
+
+/// A [Read]er that keeps a copy of all bytes it reads.
+struct RecordingReader<'a, R: Read> {
+    reader: &'a mut R,
+    bytes: Vec<u8>,
+}
+
+impl<R: Read> Read for RecordingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.bytes.extend_from_slice(&buf[..read]);
+        Ok(read)
     }
 }
 
-impl Evolvement for EidMlsEvolvement {}
+impl Evolvement for EidMlsEvolvement {
+    fn target_epoch(&self) -> u64 {
+        match self {
+            Self::OUT { epoch, .. } | Self::IN { epoch, .. } => *epoch,
+        }
+    }
+
+    fn eid_id(&self) -> EidId {
+        match self {
+            Self::OUT { eid_id, .. } | Self::IN { eid_id, .. } => eid_id.clone(),
+        }
+    }
+}
+
+impl EidMlsEvolvement {
+    /// Get the serialization of the evolvement.
+    /// An inbound evolvement returns the bytes it was deserialized from.
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EidError> {
+        match self {
+            Self::OUT { .. } => self
+                .tls_serialize_detached()
+                .map_err(|e| EidError::SerializationError(e.to_string())),
+            Self::IN { serialized, .. } => Ok(serialized.clone()),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use openmls_traits::key_store::{MlsEntity, OpenMlsKeyStore};
+use thiserror::Error;
+use tls_codec::{TlsDeserialize, TlsSerialize, TlsSize};
+
+use crate::eid_mls_storage::{EidMlsMemoryStorage, EidMlsStorage, EidMlsStorageError};
+
+/// # EID MLS Key Store
+/// Implementation of [OpenMlsKeyStore] on top of an exchangeable [EidMlsStorage].
+/// In contrast to the key store of [openmls_rust_crypto], its entries can be exported and imported again,
+/// which is needed to persist and restore an [EidMlsClient](crate::eid_mls_client::EidMlsClient).
+pub struct EidMlsKeyStore {
+    storage: Box<dyn EidMlsStorage>,
+}
+
+/// Errors of the [EidMlsKeyStore]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EidMlsKeyStoreError {
+    #[error("Error serializing key store value.")]
+    SerializationError,
+    #[error(transparent)]
+    StorageError(#[from] EidMlsStorageError),
+}
+
+/// A single raw entry of an [EidMlsKeyStore].
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidMlsKeyStoreEntry {
+    pub(crate) key: Vec<u8>,
+    pub(crate) value: Vec<u8>,
+}
+
+impl Default for EidMlsKeyStore {
+    fn default() -> Self {
+        Self::new(EidMlsMemoryStorage::default())
+    }
+}
+
+impl OpenMlsKeyStore for EidMlsKeyStore {
+    type Error = EidMlsKeyStoreError;
+
+    fn store<V: MlsEntity>(&self, k: &[u8], v: &V) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        let value = serde_json::to_vec(v).map_err(|_| EidMlsKeyStoreError::SerializationError)?;
+        self.storage.put(k, &value)?;
+        Ok(())
+    }
+
+    fn read<V: MlsEntity>(&self, k: &[u8]) -> Option<V>
+    where
+        Self: Sized,
+    {
+        self.storage
+            .get(k)
+            .ok()
+            .flatten()
+            .and_then(|value| serde_json::from_slice(&value).ok())
+    }
+
+    fn delete<V: MlsEntity>(&self, k: &[u8]) -> Result<(), Self::Error> {
+        self.storage.delete(k)?;
+        Ok(())
+    }
+}
+
+impl EidMlsKeyStore {
+    /// Create an [EidMlsKeyStore] that keeps its entries in `storage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage`: The [EidMlsStorage]
+    ///
+    /// returns: [Self]
+    pub fn new(storage: impl EidMlsStorage + 'static) -> Self {
+        Self {
+            storage: Box::new(storage),
+        }
+    }
+
+    /// Export the entries stored for `keys`. Keys without an entry are skipped.
+    pub(crate) fn export_entries(
+        &self,
+        keys: &[Vec<u8>],
+    ) -> Result<Vec<EidMlsKeyStoreEntry>, EidMlsKeyStoreError> {
+        let mut entries = vec![];
+        for key in keys {
+            if let Some(value) = self.storage.get(key)? {
+                entries.push(EidMlsKeyStoreEntry {
+                    key: key.clone(),
+                    value,
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Delete the entries stored for `keys`. Keys without an entry are skipped.
+    pub(crate) fn delete_entries(&self, keys: &[Vec<u8>]) -> Result<(), EidMlsKeyStoreError> {
+        for key in keys {
+            self.storage.delete(key)?;
+        }
+        Ok(())
+    }
+
+    /// Import entries into the key store, overwriting existing entries with the same key.
+    pub(crate) fn import_entries(
+        &self,
+        entries: Vec<EidMlsKeyStoreEntry>,
+    ) -> Result<(), EidMlsKeyStoreError> {
+        for entry in entries {
+            self.storage.put(&entry.key, &entry.value)?;
+        }
+        Ok(())
+    }
+}
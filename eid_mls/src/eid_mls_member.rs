@@ -1,6 +1,10 @@
 use openmls::prelude::{CredentialWithKey, KeyPackage, Member as MlsMember};
 
+use eid_traits::checkpoint;
 use eid_traits::member::Member;
+use eid_traits::types::EidError;
+
+use crate::eid_mls_backend::EidMlsBackend;
 
 /// # EID MLS Member
 /// Implementation of [Member] using [openmls]
@@ -43,6 +47,18 @@ impl Member for EidMlsMember {
 }
 
 impl EidMlsMember {
+    pub(crate) fn from_key_package(key_package: KeyPackage) -> Self {
+        let credential = CredentialWithKey {
+            credential: key_package.leaf_node().credential().clone(),
+            signature_key: key_package.leaf_node().signature_key().clone(),
+        };
+        Self {
+            mls_member: None,
+            key_package: Some(key_package),
+            credential,
+        }
+    }
+
     pub(crate) fn from_existing(mls_member: MlsMember) -> Self {
         let signature_key = mls_member.signature_key.clone().into();
         let credential = mls_member.credential.clone();
@@ -56,3 +72,16 @@ impl EidMlsMember {
         }
     }
 }
+
+/// Get the digest of `members` of an EID, identified by their signature keys
+/// (see [checkpoint::members_digest]).
+pub(crate) fn members_digest(
+    members: &[EidMlsMember],
+    backend: &EidMlsBackend,
+) -> Result<Vec<u8>, EidError> {
+    let signature_keys = members
+        .iter()
+        .map(|member| member.credential.signature_key.as_slice().to_vec())
+        .collect();
+    checkpoint::members_digest(backend, signature_keys)
+}
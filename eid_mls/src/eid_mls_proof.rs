@@ -0,0 +1,190 @@
+use openmls::group::PublicGroup;
+use openmls::prelude::{
+    Extensions, GroupContext, MlsMessageIn, MlsMessageInBody, Node, ProposalStore,
+};
+use tls_codec::{Deserialize, TlsDeserialize, TlsSerialize, TlsSize};
+
+use eid_traits::checkpoint::Checkpoint;
+use eid_traits::proof::EidMembershipProof;
+use eid_traits::state::EidState;
+use eid_traits::types::{EidError, EidId};
+
+use crate::eid_mls_backend::EidMlsBackend;
+use crate::eid_mls_member::{members_digest, EidMlsMember};
+use crate::state::transcript_state::EidMlsTranscriptState;
+use crate::state::{verify_cross_signatures, EidMlsCrossSignature};
+
+/// # EID MLS Membership Proof
+/// Implementation of [EidMembershipProof] using [openmls].
+/// Holds a group info signed by the member's client, the ratchet tree of the EID and the cross signatures of its members.
+/// The tree hash in the signed group context binds the ratchet tree to the epoch,
+/// and the member's cross signature shows that the member consented to being part of the EID.
+/// The cross signatures of the other members show which members a checkpoint of the EID commits to.
+#[derive(TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidMlsMembershipProof {
+    pub(crate) eid_id: EidId,
+    pub(crate) epoch: u64,
+    /// The serialized [MlsMessageOut](openmls::prelude::MlsMessageOut) holding the group info.
+    pub(crate) group_info: Vec<u8>,
+    pub(crate) nodes: Vec<Option<Node>>,
+    pub(crate) cross_signatures: Vec<EidMlsCrossSignature>,
+}
+
+impl EidMembershipProof for EidMlsMembershipProof {
+    type MemberProvider = EidMlsMember;
+    type StateProvider = EidMlsTranscriptState;
+    type BackendProvider = EidMlsBackend;
+
+    fn eid_id(&self) -> EidId {
+        self.eid_id.clone()
+    }
+
+    fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    fn verify(
+        &self,
+        checkpoint: &Checkpoint,
+        backend: &EidMlsBackend,
+    ) -> Result<EidMlsMember, EidError> {
+        self.verify_eid(&checkpoint.eid_id, checkpoint.epoch)?;
+        let (group, member) = self.verified_group(backend)?;
+        let cross_signed_members = self.cross_signed_members(&group, backend)?;
+        if members_digest(&cross_signed_members, backend)? != checkpoint.members_digest {
+            return Err(EidError::InvalidMembershipProofError(
+                "Proof doesn't show the members of the checkpoint".into(),
+            ));
+        }
+        if !cross_signed_members.contains(&member) {
+            return Err(EidError::InvalidMembershipProofError(
+                "Member is not part of the EID".into(),
+            ));
+        }
+        Ok(member)
+    }
+
+    fn verify_with_state(
+        &self,
+        state: &EidMlsTranscriptState,
+        backend: &EidMlsBackend,
+    ) -> Result<EidMlsMember, EidError> {
+        self.verify_eid(&state.eid_id(), state.epoch())?;
+        let (group, member) = self.verified_group(backend)?;
+        if group.group_context().tree_hash() != state.group.group_context().tree_hash() {
+            return Err(EidError::InvalidMembershipProofError(
+                "Proof doesn't show the group of the state".into(),
+            ));
+        }
+        if !state.verify_member(&member) {
+            return Err(EidError::InvalidMembershipProofError(
+                "Member is not part of the state".into(),
+            ));
+        }
+        Ok(member)
+    }
+}
+
+impl EidMlsMembershipProof {
+    /// Check that the proof claims membership in the EID with identifier `eid_id` in `epoch`.
+    fn verify_eid(&self, eid_id: &EidId, epoch: u64) -> Result<(), EidError> {
+        if &self.eid_id != eid_id {
+            return Err(EidError::InvalidMembershipProofError(
+                "Proof belongs to a different EID".into(),
+            ));
+        }
+        if self.epoch != epoch {
+            return Err(EidError::InvalidMembershipProofError(format!(
+                "Proof is for epoch {}, expected epoch {}",
+                self.epoch, epoch
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify the group info and the ratchet tree of the proof.
+    ///
+    /// returns: [Result]<([PublicGroup], [EidMlsMember]), [EidError]> The group and the member that signed the group info,
+    /// whose membership is proven.
+    fn verified_group(
+        &self,
+        backend: &EidMlsBackend,
+    ) -> Result<(PublicGroup, EidMlsMember), EidError> {
+        let signer = group_info_signer(&self.group_info)?;
+        let message = MlsMessageIn::tls_deserialize(&mut self.group_info.as_slice())
+            .map_err(|e| EidError::InvalidMembershipProofError(e.to_string()))?;
+        let verifiable_group_info =
+            match message.extract() {
+                MlsMessageInBody::GroupInfo(verifiable_group_info) => verifiable_group_info,
+                _ => return Err(EidError::InvalidMembershipProofError(
+                    "Expected MlsMessageInBody::GroupInfo, got another variant of MlsMessageInBody"
+                        .into(),
+                )),
+            };
+        // Verifies the signature of the group info and that the ratchet tree matches its tree hash
+        let (group, _extensions) = PublicGroup::from_external(
+            &backend.mls_backend,
+            self.nodes.clone(),
+            verifiable_group_info,
+            ProposalStore::new(),
+        )
+        .map_err(|e| EidError::InvalidMembershipProofError(e.to_string()))?;
+        let group_context = group.group_context();
+        if group_context.group_id().as_slice() != self.eid_id.as_slice()
+            || group_context.epoch().as_u64() != self.epoch
+        {
+            return Err(EidError::InvalidMembershipProofError(
+                "Group info doesn't match the EID and epoch of the proof".into(),
+            ));
+        }
+        let member = group
+            .members()
+            .find(|member| member.index.u32() == signer)
+            .map(EidMlsMember::from_existing)
+            .ok_or_else(|| {
+                EidError::InvalidMembershipProofError("Signer is not part of the EID".into())
+            })?;
+        Ok((group, member))
+    }
+
+    /// Verify the cross signatures of the proof and get the members of the `group` that have cross-signed their membership.
+    fn cross_signed_members(
+        &self,
+        group: &PublicGroup,
+        backend: &EidMlsBackend,
+    ) -> Result<Vec<EidMlsMember>, EidError> {
+        let members: Vec<_> = group.members().collect();
+        verify_cross_signatures(
+            self.eid_id.as_slice(),
+            &self.cross_signatures,
+            &members,
+            group.group_context().ciphersuite().signature_algorithm(),
+            backend,
+        )
+        .map_err(|e| EidError::InvalidMembershipProofError(e.to_string()))?;
+        Ok(members
+            .into_iter()
+            .filter(|member| {
+                self.cross_signatures
+                    .iter()
+                    .any(|cross_signature| cross_signature.signature_key == member.signature_key)
+            })
+            .map(EidMlsMember::from_existing)
+            .collect())
+    }
+}
+
+/// Read the leaf index of the member that signed a serialized group info.
+/// [openmls] doesn't expose the signer of a group info, so it is read from the serialized message:
+/// the protocol version and the wire format, followed by the group context, the extensions,
+/// the confirmation tag and the signer of the group info.
+fn group_info_signer(group_info: &[u8]) -> Result<u32, EidError> {
+    let bytes = &mut &group_info[..];
+    u16::tls_deserialize(bytes)
+        .and_then(|_| u16::tls_deserialize(bytes))
+        .and_then(|_| GroupContext::tls_deserialize(bytes))
+        .and_then(|_| Extensions::tls_deserialize(bytes))
+        .and_then(|_| Vec::<u8>::tls_deserialize(bytes))
+        .and_then(|_| u32::tls_deserialize(bytes))
+        .map_err(|e| EidError::InvalidMembershipProofError(e.to_string()))
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{ErrorKind, Write};
+#[cfg(unix)]
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use thiserror::Error;
+
+/// # EID MLS Storage
+/// Raw storage of key-value pairs that backs an [EidMlsKeyStore](crate::eid_mls_key_store::EidMlsKeyStore).
+/// Implement this trait to persist the key material of an [EidMlsBackend](crate::eid_mls_backend::EidMlsBackend)
+/// in a storage of your choice.
+pub trait EidMlsStorage: Send + Sync {
+    /// Get the value stored for `key`, if any.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, EidMlsStorageError>;
+
+    /// Store `value` for `key`, overwriting any existing value.
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), EidMlsStorageError>;
+
+    /// Delete the value stored for `key`. Deleting a non-existent key is not an error.
+    fn delete(&self, key: &[u8]) -> Result<(), EidMlsStorageError>;
+
+    /// Get all key-value pairs in the storage.
+    fn entries(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>, EidMlsStorageError>;
+}
+
+/// Errors of an [EidMlsStorage]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EidMlsStorageError {
+    #[error("The storage lock is poisoned.")]
+    LockError,
+    #[error("IO error: {0}")]
+    IoError(String),
+    #[error("Invalid storage entry: {0}")]
+    InvalidEntryError(String),
+}
+
+/// # EID MLS Memory Storage
+/// [EidMlsStorage] that keeps all entries in memory. All entries are lost when it is dropped.
+#[derive(Debug, Default)]
+pub struct EidMlsMemoryStorage {
+    values: RwLock<HashMap<Vec<u8>, Vec<u8>>>,
+}
+
+impl EidMlsStorage for EidMlsMemoryStorage {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, EidMlsStorageError> {
+        let values = self
+            .values
+            .read()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        Ok(values.get(key).cloned())
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), EidMlsStorageError> {
+        let mut values = self
+            .values
+            .write()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        values.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), EidMlsStorageError> {
+        let mut values = self
+            .values
+            .write()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        values.remove(key);
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>, EidMlsStorageError> {
+        let values = self
+            .values
+            .read()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        Ok(values
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+}
+
+/// The extension of the temporary file an entry is written to before it replaces the entry's file.
+const TEMP_EXTENSION: &str = "tmp";
+
+/// # EID MLS File Storage
+/// [EidMlsStorage] that stores every entry as a file in a directory, so entries survive process restarts.
+/// The file name of an entry is the hex encoding of its key.
+///
+/// Entries hold private keys, so on Unix the files are only readable by the owner (mode `0600`)
+/// and a directory that is created by the storage is only accessible by the owner (mode `0700`).
+/// The permissions of an existing directory aren't changed.
+/// An entry is written to a temporary file that replaces the entry's file once it is synced to disk,
+/// so an entry is never left partially written.
+#[derive(Debug)]
+pub struct EidMlsFileStorage {
+    directory: PathBuf,
+    lock: RwLock<()>,
+}
+
+impl EidMlsFileStorage {
+    /// Create a [EidMlsFileStorage] in `directory`. The directory is created if it doesn't exist.
+    /// Entries that already exist in the directory are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory`: The directory the entries are stored in
+    ///
+    /// returns: [Result]<[Self], [EidMlsStorageError]>
+    pub fn new(directory: impl Into<PathBuf>) -> Result<Self, EidMlsStorageError> {
+        let directory = directory.into();
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        builder.mode(0o700);
+        builder
+            .create(&directory)
+            .map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+        Ok(Self {
+            directory,
+            lock: RwLock::new(()),
+        })
+    }
+
+    fn path(&self, key: &[u8]) -> PathBuf {
+        self.directory.join(hex_encode(key))
+    }
+}
+
+impl EidMlsStorage for EidMlsFileStorage {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, EidMlsStorageError> {
+        let _guard = self
+            .lock
+            .read()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        match fs::read(self.path(key)) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(EidMlsStorageError::IoError(e.to_string())),
+        }
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), EidMlsStorageError> {
+        let _guard = self
+            .lock
+            .write()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        let path = self.path(key);
+        let temp_path = path.with_extension(TEMP_EXTENSION);
+        // A temporary file left behind by a crash may have other permissions, so we always create a new one
+        match fs::remove_file(&temp_path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(EidMlsStorageError::IoError(e.to_string())),
+        }
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options
+            .open(&temp_path)
+            .map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+        file.write_all(value)
+            .and_then(|_| file.sync_all())
+            .map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+        fs::rename(&temp_path, &path).map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+        // Sync the directory, so that the rename itself is persisted
+        #[cfg(unix)]
+        fs::File::open(&self.directory)
+            .and_then(|directory| directory.sync_all())
+            .map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+        Ok(())
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), EidMlsStorageError> {
+        let _guard = self
+            .lock
+            .write()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        match fs::remove_file(self.path(key)) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(EidMlsStorageError::IoError(e.to_string())),
+        }
+    }
+
+    fn entries(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>, EidMlsStorageError> {
+        let _guard = self
+            .lock
+            .read()
+            .map_err(|_| EidMlsStorageError::LockError)?;
+        let dir_entries = fs::read_dir(&self.directory)
+            .map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+        let mut entries = vec![];
+        for dir_entry in dir_entries {
+            let dir_entry = dir_entry.map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+            // Skip temporary files of writes that didn't finish
+            if dir_entry.path().extension() == Some(OsStr::new(TEMP_EXTENSION)) {
+                continue;
+            }
+            let file_name = dir_entry.file_name();
+            let file_name = file_name.to_str().ok_or_else(|| {
+                EidMlsStorageError::InvalidEntryError("File name is not valid UTF-8".into())
+            })?;
+            let key = hex_decode(file_name)?;
+            let value = fs::read(dir_entry.path())
+                .map_err(|e| EidMlsStorageError::IoError(e.to_string()))?;
+            entries.push((key, value));
+        }
+        Ok(entries)
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn hex_decode(string: &str) -> Result<Vec<u8>, EidMlsStorageError> {
+    if string.len() % 2 != 0 || !string.is_ascii() {
+        return Err(EidMlsStorageError::InvalidEntryError(format!(
+            "Invalid key {}",
+            string
+        )));
+    }
+    (0..string.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&string[i..i + 2], 16).map_err(|_| {
+                EidMlsStorageError::InvalidEntryError(format!("Invalid key {}", string))
+            })
+        })
+        .collect()
+}
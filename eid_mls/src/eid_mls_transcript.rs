@@ -1,10 +1,13 @@
+use eid_traits::backend::EidBackend;
+use eid_traits::evolvement::{EvolvementSummary, EvolvementVerdict};
+use eid_traits::merkle;
 use eid_traits::state::EidState;
-use eid_traits::transcript::EidTranscript;
-use eid_traits::types::EidError;
+use eid_traits::transcript::{EidTranscript, LogHashInput, LogPrefix};
+use eid_traits::types::{ChallengeResponse, EidError, EidId, MembershipEvent};
 
 use crate::eid_mls_backend::EidMlsBackend;
 use crate::eid_mls_evolvement::EidMlsEvolvement;
-use crate::eid_mls_member::EidMlsMember;
+use crate::eid_mls_member::{members_digest, EidMlsMember};
 use crate::state::transcript_state::EidMlsTranscriptState;
 
 /// # EID MLS Transcript
@@ -14,6 +17,10 @@ pub struct EidMlsTranscript {
     trusted_state: EidMlsTranscriptState,
     current_state: EidMlsTranscriptState,
     log: Vec<EidMlsEvolvement>,
+    /// The heads of the hash chain over the log, starting with the head of the trusted state
+    /// or the head of the pruned prefix if the transcript was compacted.
+    heads: Vec<Vec<u8>>,
+    prefix: Option<LogPrefix>,
 }
 
 impl EidTranscript for EidMlsTranscript {
@@ -22,15 +29,23 @@ impl EidTranscript for EidMlsTranscript {
     type BackendProvider = EidMlsBackend;
     type StateProvider = EidMlsTranscriptState;
 
-    fn new(
+    fn new_with_prefix(
         trusted_state: Self::StateProvider,
+        prefix: Option<LogPrefix>,
         log: Vec<Self::EvolvementProvider>,
         backend: &Self::BackendProvider,
     ) -> Result<Self, EidError> {
+        // A compacted log continues the hash chain of its pruned prefix
+        let root = match &prefix {
+            Some(prefix) => prefix.head_hash.clone(),
+            None => Self::root(&trusted_state, backend)?,
+        };
         let mut transcript = EidMlsTranscript {
             trusted_state: trusted_state.clone_serde()?,
             current_state: trusted_state,
             log: vec![],
+            heads: vec![root],
+            prefix,
         };
         transcript.batch_evolve(log, backend)?;
         Ok(transcript)
@@ -40,20 +55,216 @@ impl EidTranscript for EidMlsTranscript {
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError> {
-        self.current_state.apply(evolvement.clone(), backend)?;
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        let head = Self::chain(&self.head_hash(), &evolvement, backend)?;
+        let events = self.current_state.apply(evolvement.clone(), backend)?;
         self.log.push(evolvement);
-        Ok(())
+        self.heads.push(head);
+        Ok(events)
+    }
+
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict {
+        self.current_state.validate(evolvement, backend)
+    }
+
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError> {
+        self.current_state.describe(evolvement, backend)
+    }
+
+    fn batch_evolve(
+        &mut self,
+        evolvements: Vec<Self::EvolvementProvider>,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        let snapshot = self.current_state.clone_serde()?;
+        let log_length = self.log.len();
+        let mut events = vec![];
+        for evolvement in evolvements {
+            match self.evolve(evolvement, backend) {
+                Ok(evolvement_events) => events.extend(evolvement_events),
+                Err(error) => {
+                    self.current_state = snapshot;
+                    self.log.truncate(log_length);
+                    self.heads.truncate(log_length + 1);
+                    return Err(error);
+                }
+            }
+        }
+        Ok(events)
     }
 
     fn log(&self) -> Vec<Self::EvolvementProvider> {
         self.log.clone()
     }
 
+    fn log_prefix(&self) -> Option<LogPrefix> {
+        self.prefix.clone()
+    }
+
+    fn head_hash(&self) -> Vec<u8> {
+        self.heads.last().cloned().unwrap_or_default()
+    }
+
+    fn head_hash_at(&self, log_size: u64) -> Option<Vec<u8>> {
+        let pruned = self.prefix.as_ref().map_or(0, |prefix| prefix.size);
+        log_size
+            .checked_sub(pruned)
+            .and_then(|size| self.heads.get(size as usize).cloned())
+    }
+
+    fn verify_log(&self, backend: &Self::BackendProvider) -> Result<(), EidError> {
+        if self.heads.len() != self.log.len() + 1 {
+            return Err(EidError::InvalidLogError(
+                "The log doesn't match the hash chain".into(),
+            ));
+        }
+        let mut state = self.trusted_state.clone_serde()?;
+        let mut head = match &self.prefix {
+            Some(prefix) => prefix.head_hash.clone(),
+            None => Self::root(&state, backend)?,
+        };
+        if head != self.heads[0] {
+            return Err(EidError::InvalidLogError(
+                "The trusted state doesn't match the hash chain".into(),
+            ));
+        }
+        for (index, (evolvement, expected_head)) in
+            self.log.iter().zip(&self.heads[1..]).enumerate()
+        {
+            head = Self::chain(&head, evolvement, backend)?;
+            if &head != expected_head {
+                return Err(EidError::InvalidLogError(format!(
+                    "Entry {} of the log doesn't match the hash chain",
+                    index
+                )));
+            }
+            state.apply(evolvement.clone(), backend).map_err(|e| {
+                EidError::InvalidLogError(format!(
+                    "Entry {} of the log can't be applied: {}",
+                    index, e
+                ))
+            })?;
+        }
+        if state.epoch() != self.current_state.epoch()
+            || state.get_members() != self.current_state.get_members()
+        {
+            return Err(EidError::InvalidLogError(
+                "The replayed log doesn't match the current state".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn compact(
+        &mut self,
+        up_to_epoch: u64,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<Self::EvolvementProvider>, EidError> {
+        let mut state = self.trusted_state.clone_serde()?;
+        let mut entries = self.log.iter();
+        let mut pruned = 0;
+        while state.epoch() != up_to_epoch {
+            let evolvement = entries.next().ok_or_else(|| {
+                EidError::CompactTranscriptError(format!(
+                    "The log has no state at epoch {}",
+                    up_to_epoch
+                ))
+            })?;
+            state.apply(evolvement.clone(), backend)?;
+            pruned += 1;
+        }
+        if pruned == 0 {
+            return Ok(vec![]);
+        }
+        let size = self.prefix.as_ref().map_or(0, |prefix| prefix.size) + pruned as u64;
+        let archived = self.log.drain(..pruned).collect();
+        self.heads.drain(..pruned);
+        self.prefix = Some(LogPrefix {
+            size,
+            head_hash: self.heads[0].clone(),
+        });
+        self.trusted_state = state;
+        Ok(archived)
+    }
+
+    fn leaf_hash(
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<u8>, EidError> {
+        merkle::leaf_hash(backend, &evolvement.to_bytes()?)
+    }
+
+    fn members_digest(&self, backend: &Self::BackendProvider) -> Result<Vec<u8>, EidError> {
+        members_digest(&self.get_members(), backend)
+    }
+
+    #[cfg(feature = "test")]
+    fn log_mut(&mut self) -> &mut Vec<Self::EvolvementProvider> {
+        &mut self.log
+    }
+
+    fn verify_challenge(
+        &self,
+        nonce: &[u8],
+        response: &ChallengeResponse,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError> {
+        self.current_state
+            .verify_challenge(nonce, response, backend)
+    }
+
     fn get_members(&self) -> Vec<Self::MemberProvider> {
         self.current_state.get_members()
     }
+
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider> {
+        self.current_state.get_pending_members()
+    }
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider> {
+        self.current_state.get_expired_members(now)
+    }
+    fn epoch(&self) -> u64 {
+        self.current_state.epoch()
+    }
+    fn eid_id(&self) -> EidId {
+        self.current_state.eid_id()
+    }
     fn get_trusted_state(&self) -> Result<Self::StateProvider, EidError> {
         self.trusted_state.clone_serde()
     }
 }
+
+impl EidMlsTranscript {
+    /// Get the first head of the hash chain of a log that starts with `trusted_state`.
+    /// It commits to the cross-signed and the pending members of the state.
+    fn root(
+        trusted_state: &EidMlsTranscriptState,
+        backend: &EidMlsBackend,
+    ) -> Result<Vec<u8>, EidError> {
+        let members = [
+            trusted_state.get_members(),
+            trusted_state.get_pending_members(),
+        ]
+        .concat();
+        let members_digest = members_digest(&members, backend)?;
+        backend.hash(&LogHashInput::root(trusted_state, members_digest).to_bytes()?)
+    }
+
+    /// Get the head of the hash chain after `evolvement` is appended to a log with the head `previous_head`.
+    fn chain(
+        previous_head: &[u8],
+        evolvement: &EidMlsEvolvement,
+        backend: &EidMlsBackend,
+    ) -> Result<Vec<u8>, EidError> {
+        let input = LogHashInput::entry(previous_head, evolvement, evolvement.to_bytes()?);
+        backend.hash(&input.to_bytes()?)
+    }
+}
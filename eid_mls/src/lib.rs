@@ -2,7 +2,11 @@ extern crate core;
 
 pub mod eid_mls_backend;
 pub mod eid_mls_client;
+pub mod eid_mls_config;
 pub mod eid_mls_evolvement;
+pub mod eid_mls_key_store;
 pub mod eid_mls_member;
+pub mod eid_mls_proof;
+pub mod eid_mls_storage;
 pub mod eid_mls_transcript;
 pub mod state;
@@ -1,38 +1,211 @@
 use openmls::framing::MlsMessageInBody;
-use openmls::framing::ProcessedMessageContent::StagedCommitMessage;
-use openmls::prelude::{LeafNode, Member as MlsMember};
+use openmls::framing::ProcessedMessageContent::{ProposalMessage, StagedCommitMessage};
+use openmls::prelude::{Member as MlsMember, MlsMessageIn, Sender};
 use openmls::prelude::{
-    MlsGroup, Node, ProcessMessageError, ProcessedMessage, ProtocolMessage, StageCommitError,
+    MlsGroup, ProcessMessageError, ProcessedMessage, ProtocolMessage, SignatureScheme,
+    StageCommitError,
 };
+use openmls_traits::OpenMlsCryptoProvider;
+use tls_codec::{Serialize, TlsDeserialize, TlsSerialize, TlsSize};
 
+use eid_traits::evolvement::{EvolvementSummary, EvolvementVerdict};
 use eid_traits::state::EidState;
-use eid_traits::types::EidError;
+use eid_traits::types::{ChallengeResponse, EidChange, EidError, EidId, MembershipEvent};
 
 use crate::eid_mls_backend::EidMlsBackend;
 use crate::eid_mls_evolvement::EidMlsEvolvement;
+use crate::eid_mls_key_store::EidMlsKeyStoreEntry;
 use crate::eid_mls_member::EidMlsMember;
+use crate::state::{
+    commit_cross_signature, commit_summary, cross_signatures_after_commit, cross_signed_members,
+    error_verdict, invitations_after_commit, is_invitation_expired, membership_events,
+    message_verdict, proposal_summary, sender_member, staged_commit_change,
+    update_cross_signatures, update_invitations, verify_challenge_response,
+    EidMlsAuthenticatedData, EidMlsCrossSignature, EidMlsInvitation, EidMlsSignedState,
+};
 
 /// # EidClientState
 /// Implementation of [EidState] using [openmls], wrapping a [MlsGroup].
 pub struct EidMlsClientState {
     pub(crate) group: MlsGroup,
+    /// Cross signatures of all members that have cross-signed their membership.
+    pub(crate) cross_signatures: Vec<EidMlsCrossSignature>,
+    /// Invitations of pending members that expire.
+    pub(crate) invitations: Vec<EidMlsInvitation>,
+    /// Authenticated data of our own pending commit.
+    pub(crate) pending_authenticated_data: Option<EidMlsAuthenticatedData>,
+}
+
+/// # EidMlsClientStateSnapshot
+/// A copy of an [EidMlsClientState] and of the key store entries of its group,
+/// taken before a change that may fail part way (see [EidMlsClientState::roll_back]).
+pub(crate) struct EidMlsClientStateSnapshot {
+    state: EidMlsClientState,
+    keys: Vec<Vec<u8>>,
+    entries: Vec<EidMlsKeyStoreEntry>,
 }
 
 impl EidMlsClientState {
+    pub(crate) fn new(group: MlsGroup) -> Self {
+        Self {
+            group,
+            cross_signatures: vec![],
+            invitations: vec![],
+            pending_authenticated_data: None,
+        }
+    }
+
+    /// Get the keys of the key store entries that hold the private key material of the client's leaf,
+    /// i.e. the key pair of its leaf's encryption key and the key pairs of the current epoch.
+    /// The key of the epoch key pairs mirrors the one [openmls] uses: the serialized group id,
+    /// followed by the epoch and the leaf index.
+    /// The signature key pair isn't included, since it is exported separately.
+    pub(crate) fn key_store_keys(&self) -> Result<Vec<Vec<u8>>, EidError> {
+        let own_index = self.group.own_leaf_index();
+        let mut keys = vec![];
+        if let Some(own_member) = self
+            .group
+            .members()
+            .find(|member| member.index == own_index)
+        {
+            keys.push(own_member.encryption_key);
+        }
+        let mut epoch_key_pairs_id = self
+            .group
+            .group_id()
+            .tls_serialize_detached()
+            .map_err(|e| EidError::SerializationError(e.to_string()))?;
+        epoch_key_pairs_id.extend_from_slice(&self.group.epoch().as_u64().to_be_bytes());
+        epoch_key_pairs_id.extend_from_slice(&own_index.u32().to_be_bytes());
+        keys.push(epoch_key_pairs_id);
+        Ok(keys)
+    }
+
+    /// Take a snapshot of the state and of the key store entries of its group.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [EidMlsBackend] that holds the key store
+    ///
+    /// returns: [Result]<[EidMlsClientStateSnapshot], [EidError]>
+    pub(crate) fn snapshot(
+        &self,
+        backend: &EidMlsBackend,
+    ) -> Result<EidMlsClientStateSnapshot, EidError> {
+        let keys = self.key_store_keys()?;
+        let entries = backend
+            .mls_backend
+            .key_store()
+            .export_entries(&keys)
+            .map_err(|e| EidError::ExportClientStateError(e.to_string()))?;
+        let state = Self {
+            group: self.clone_group()?,
+            cross_signatures: self.cross_signatures.clone(),
+            invitations: self.invitations.clone(),
+            pending_authenticated_data: self.pending_authenticated_data.clone(),
+        };
+        Ok(EidMlsClientStateSnapshot {
+            state,
+            keys,
+            entries,
+        })
+    }
+
+    /// Roll back to `snapshot` after a change failed with `error`.
+    /// The state is always restored. Afterwards the key store entries of the epoch the change reached are deleted
+    /// and the ones the snapshot holds are put back.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot`: The [EidMlsClientStateSnapshot] taken before the change
+    /// * `error`: The [EidError] the change failed with
+    /// * `backend`: The [EidMlsBackend] that holds the key store
+    ///
+    /// returns: [EidError] `error`, or an [EidError::RestoreClientStateError] that reports both failures
+    /// if the key store couldn't be restored.
+    pub(crate) fn roll_back(
+        &mut self,
+        snapshot: EidMlsClientStateSnapshot,
+        error: EidError,
+        backend: &EidMlsBackend,
+    ) -> EidError {
+        let added_keys = self.key_store_keys().map(|keys| {
+            keys.into_iter()
+                .filter(|key| !snapshot.keys.contains(key))
+                .collect::<Vec<_>>()
+        });
+        *self = snapshot.state;
+        let key_store = backend.mls_backend.key_store();
+        let restored = added_keys.and_then(|added_keys| {
+            key_store
+                .delete_entries(&added_keys)
+                .and_then(|_| key_store.import_entries(snapshot.entries))
+                .map_err(|e| EidError::RestoreClientStateError(e.to_string()))
+        });
+        match restored {
+            Ok(()) => error,
+            Err(restore_error) => EidError::RestoreClientStateError(format!(
+                "Failed to restore the key store ({}) after: {}",
+                restore_error, error
+            )),
+        }
+    }
+
+    /// Merge a staged commit or store a proposal.
+    /// Own proposals are already in the proposal store and aren't stored twice.
+    /// A commit is verified against the `signed_state` it was received with (see [Self::verify_commit]).
     fn apply_processed_message(
         &mut self,
         message: ProcessedMessage,
+        signed_state: Option<&EidMlsSignedState>,
         backend: &EidMlsBackend,
-    ) -> Result<(), EidError> {
-        if let StagedCommitMessage(staged_commit_ref) = message.into_content() {
-            self.group
-                .merge_staged_commit(&backend.mls_backend, *staged_commit_ref)
-                .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
-            Ok(())
-        } else {
-            Err(EidError::InvalidEvolvementError(
-                "Expected ProcessedMessage::StagedCommitMessage, got a different variant of a processed message".into(),
-            ))
+    ) -> Result<Vec<MembershipEvent<EidMlsMember>>, EidError> {
+        let own_message = matches!(
+            message.sender(),
+            Sender::Member(index) if *index == self.group.own_leaf_index()
+        );
+        let members: Vec<_> = self.group.members().collect();
+        let sender = sender_member(&members, message.sender());
+        let aad = message.aad().to_vec();
+        match message.into_content() {
+            StagedCommitMessage(staged_commit_ref) => {
+                if self.group.pending_commit().is_some() {
+                    return Err(EidError::ConflictingEvolvementError(
+                        "Commit competes with the pending commit".into(),
+                    ));
+                }
+                let authenticated_data = EidMlsAuthenticatedData::from_aad(&aad)?;
+                let change = staged_commit_change(&staged_commit_ref, &members);
+                let cross_signature = commit_cross_signature(
+                    self.eid_id().as_slice(),
+                    authenticated_data.as_ref(),
+                    sender.as_ref(),
+                    self.signature_scheme(),
+                    backend,
+                )?;
+                self.verify_commit(
+                    sender.as_ref(),
+                    &change,
+                    authenticated_data.as_ref(),
+                    cross_signature.as_ref(),
+                    signed_state,
+                )?;
+                let cross_signed_members = self.cross_signed_members();
+                self.group
+                    .merge_staged_commit(&backend.mls_backend, *staged_commit_ref)
+                    .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+                self.update_after_commit(&change, authenticated_data, cross_signature);
+                Ok(self.membership_events(change, &members, &cross_signed_members))
+            }
+            ProposalMessage(queued_proposal) => {
+                if !own_message {
+                    self.group.store_pending_proposal(*queued_proposal);
+                }
+                Ok(vec![])
+            }
+            _ => Err(EidError::InvalidEvolvementError(
+                "Expected ProcessedMessage::StagedCommitMessage or ::ProposalMessage, got a different variant of a processed message".into(),
+            )),
         }
     }
 }
@@ -46,21 +219,38 @@ impl EidState for EidMlsClientState {
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError> {
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        self.verify_eid(&evolvement)?;
+        self.verify_epoch(&evolvement)?;
         if let EidMlsEvolvement::IN {
-            message: mls_in, ..
+            message: mls_in,
+            proposals,
+            signed_state,
+            ..
         } = evolvement
         {
-            let body = mls_in.extract();
-            if let MlsMessageInBody::PublicMessage(public_message) = body {
-                let protocol_message = ProtocolMessage::PublicMessage(public_message);
-
-                self.merge_or_apply_commit(protocol_message, backend)
-            } else {
-                Err(EidError::InvalidEvolvementError(
-                    "Expected MlsMessageInBody::PublicMessage, got another variant".into(),
-                ))
+            if proposals.is_empty() {
+                let protocol_message = Self::protocol_message(mls_in)?;
+                return self.merge_or_apply_commit(
+                    protocol_message,
+                    signed_state.as_ref(),
+                    backend,
+                );
             }
+            // The carried proposals are stored before the commit is processed,
+            // so they are rolled back together with the group if the commit fails
+            let snapshot = self.snapshot(backend)?;
+            let result = proposals
+                .into_iter()
+                .try_for_each(|proposal| {
+                    let protocol_message = Self::protocol_message(proposal)?;
+                    self.apply_proposal(protocol_message, backend)
+                })
+                .and_then(|_| {
+                    let protocol_message = Self::protocol_message(mls_in)?;
+                    self.merge_or_apply_commit(protocol_message, signed_state.as_ref(), backend)
+                });
+            result.map_err(|error| self.roll_back(snapshot, error, backend))
         } else {
             Err(EidError::InvalidEvolvementError(String::from(
                 "Expected EidMlsEvolvement::IN, got ::OUT",
@@ -68,39 +258,350 @@ impl EidState for EidMlsClientState {
         }
     }
 
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict {
+        if let Err(error) = self
+            .verify_eid(evolvement)
+            .and_then(|_| self.verify_epoch(evolvement))
+        {
+            return error.into();
+        }
+        let (message, proposals, signed_state) = match evolvement {
+            EidMlsEvolvement::IN {
+                message,
+                proposals,
+                signed_state,
+                ..
+            } => (message, proposals, signed_state),
+            EidMlsEvolvement::OUT { .. } => {
+                return EvolvementVerdict::Invalid(
+                    "Expected EidMlsEvolvement::IN, got ::OUT".into(),
+                )
+            }
+        };
+        // Processing messages needs a mutable group, so we work on a copy
+        let mut group = match self.clone_group() {
+            Ok(group) => group,
+            Err(error) => return error.into(),
+        };
+        for proposal in proposals {
+            let protocol_message = match Self::protocol_message(proposal.clone()) {
+                Ok(protocol_message) => protocol_message,
+                Err(error) => return error.into(),
+            };
+            let processed_message =
+                match group.process_message(&backend.mls_backend, protocol_message) {
+                    Ok(processed_message) => processed_message,
+                    Err(error) => return error_verdict(&error),
+                };
+            let verdict = message_verdict(&processed_message);
+            if !verdict.is_valid() {
+                return verdict;
+            }
+            let own_message = matches!(
+                processed_message.sender(),
+                Sender::Member(index) if *index == group.own_leaf_index()
+            );
+            match processed_message.into_content() {
+                ProposalMessage(queued_proposal) => {
+                    if !own_message {
+                        group.store_pending_proposal(*queued_proposal);
+                    }
+                }
+                _ => return EvolvementVerdict::Invalid("Expected a proposal.".into()),
+            }
+        }
+        let protocol_message = match Self::protocol_message(message.clone()) {
+            Ok(protocol_message) => protocol_message,
+            Err(error) => return error.into(),
+        };
+        match group.process_message(&backend.mls_backend, protocol_message) {
+            Ok(processed_message) => {
+                let is_commit = matches!(processed_message.content(), StagedCommitMessage(_));
+                if is_commit && group.pending_commit().is_some() {
+                    return EidError::ConflictingEvolvementError(
+                        "Commit competes with the pending commit".into(),
+                    )
+                    .into();
+                }
+                let verdict = message_verdict(&processed_message);
+                if !verdict.is_valid() {
+                    return verdict;
+                }
+                if let StagedCommitMessage(staged_commit) = processed_message.content() {
+                    let members: Vec<_> = self.group.members().collect();
+                    let sender = sender_member(&members, processed_message.sender());
+                    let authenticated_data =
+                        match EidMlsAuthenticatedData::from_aad(processed_message.aad()) {
+                            Ok(authenticated_data) => authenticated_data,
+                            Err(error) => return EvolvementVerdict::Invalid(error.to_string()),
+                        };
+                    // The same checks as when the commit is applied
+                    let change = staged_commit_change(staged_commit, &members);
+                    let cross_signature = match commit_cross_signature(
+                        self.eid_id().as_slice(),
+                        authenticated_data.as_ref(),
+                        sender.as_ref(),
+                        self.signature_scheme(),
+                        backend,
+                    ) {
+                        Ok(cross_signature) => cross_signature,
+                        Err(_) => return EvolvementVerdict::BadSignature,
+                    };
+                    if let Err(error) = self.verify_commit(
+                        sender.as_ref(),
+                        &change,
+                        authenticated_data.as_ref(),
+                        cross_signature.as_ref(),
+                        signed_state.as_ref(),
+                    ) {
+                        return error.into();
+                    }
+                }
+                verdict
+            }
+            // Our own commit is merged from the pending commit
+            Err(ProcessMessageError::InvalidCommit(StageCommitError::OwnCommit))
+                if group.pending_commit().is_some() =>
+            {
+                EvolvementVerdict::Valid
+            }
+            Err(error) => error_verdict(&error),
+        }
+    }
+
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError> {
+        self.verify_eid(evolvement)?;
+        self.verify_epoch(evolvement)?;
+        let (message, proposals) = match evolvement {
+            EidMlsEvolvement::IN {
+                message, proposals, ..
+            } => (message, proposals),
+            EidMlsEvolvement::OUT { .. } => {
+                return Err(EidError::InvalidEvolvementError(String::from(
+                    "Expected EidMlsEvolvement::IN, got ::OUT",
+                )))
+            }
+        };
+        let members: Vec<_> = self.group.members().collect();
+        // Processing messages needs a mutable group, so we work on a copy
+        let mut group = self.clone_group()?;
+        for proposal in proposals {
+            let protocol_message = Self::protocol_message(proposal.clone())?;
+            let processed_message = group
+                .process_message(&backend.mls_backend, protocol_message)
+                .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+            let own_message = matches!(
+                processed_message.sender(),
+                Sender::Member(index) if *index == group.own_leaf_index()
+            );
+            if let ProposalMessage(queued_proposal) = processed_message.into_content() {
+                if !own_message {
+                    group.store_pending_proposal(*queued_proposal);
+                }
+            } else {
+                return Err(EidError::InvalidEvolvementError(
+                    "Expected a proposal.".into(),
+                ));
+            }
+        }
+        let protocol_message = Self::protocol_message(message.clone())?;
+        match group.process_message(&backend.mls_backend, protocol_message) {
+            Ok(processed_message) => {
+                let sender = sender_member(&members, processed_message.sender());
+                let authenticated_data =
+                    EidMlsAuthenticatedData::from_aad(processed_message.aad())?;
+                let cross_signs = matches!(
+                    authenticated_data,
+                    Some(EidMlsAuthenticatedData::CrossSignature(_))
+                );
+                match processed_message.content() {
+                    StagedCommitMessage(staged_commit) => Ok(commit_summary(
+                        staged_commit,
+                        &members,
+                        sender,
+                        cross_signs,
+                        self.epoch() + 1,
+                    )),
+                    ProposalMessage(queued_proposal) => Ok(proposal_summary(
+                        queued_proposal,
+                        &members,
+                        sender,
+                        self.epoch(),
+                    )),
+                    _ => Err(EidError::InvalidEvolvementError(
+                        "Expected ProcessedMessage::StagedCommitMessage or ::ProposalMessage, got a different variant of a processed message".into(),
+                    )),
+                }
+            }
+            // Our own commit is described by the pending commit
+            Err(ProcessMessageError::InvalidCommit(StageCommitError::OwnCommit)) => {
+                let staged_commit = self.group.pending_commit().ok_or_else(|| {
+                    EidError::InvalidEvolvementError("Own commit is not pending".into())
+                })?;
+                let sender = sender_member(&members, &Sender::Member(self.group.own_leaf_index()));
+                let cross_signs = matches!(
+                    self.pending_authenticated_data,
+                    Some(EidMlsAuthenticatedData::CrossSignature(_))
+                );
+                Ok(commit_summary(
+                    staged_commit,
+                    &members,
+                    sender,
+                    cross_signs,
+                    self.epoch() + 1,
+                ))
+            }
+            Err(e) => Err(EidError::InvalidEvolvementError(e.to_string())),
+        }
+    }
+
+    /// The group id is the [EidId], which members sign when cross-signing their membership.
+    fn eid_id(&self) -> EidId {
+        EidId::new(self.group.group_id().as_slice().to_vec())
+    }
+
+    fn epoch(&self) -> u64 {
+        self.group.epoch().as_u64()
+    }
+
+    fn verify_challenge(
+        &self,
+        nonce: &[u8],
+        response: &ChallengeResponse,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError> {
+        verify_challenge_response(
+            &self.eid_id(),
+            self.get_members(),
+            nonce,
+            response,
+            self.signature_scheme(),
+            backend,
+        )
+    }
+
     fn get_members(&self) -> Vec<Self::MemberProvider> {
-        // get members out of group state -> members leaf node sources which are not a key package but a commit, will be valid members
+        // only members with a verified cross signature are valid members
         self.group
             .members()
             .filter(|member| self.has_member(member).unwrap_or(false))
             .map(EidMlsMember::from_existing)
             .collect()
     }
+
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider> {
+        self.group
+            .members()
+            .filter(|member| !self.has_member(member).unwrap_or(true))
+            .map(EidMlsMember::from_existing)
+            .collect()
+    }
+
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider> {
+        self.get_pending_members()
+            .into_iter()
+            .filter(|member| self.is_expired(member, now))
+            .collect()
+    }
 }
 
 impl EidMlsClientState {
-    fn merge_or_apply_commit(
+    fn clone_group(&self) -> Result<MlsGroup, EidError> {
+        let serialized = serde_json::to_vec(&self.group)
+            .map_err(|e| EidError::SerializationError(e.to_string()))?;
+        serde_json::from_slice(&serialized)
+            .map_err(|e| EidError::DeserializationError(e.to_string()))
+    }
+
+    fn protocol_message(mls_in: MlsMessageIn) -> Result<ProtocolMessage, EidError> {
+        let body = mls_in.extract();
+        if let MlsMessageInBody::PublicMessage(public_message) = body {
+            Ok(ProtocolMessage::PublicMessage(public_message))
+        } else {
+            Err(EidError::InvalidEvolvementError(
+                "Expected MlsMessageInBody::PublicMessage, got another variant".into(),
+            ))
+        }
+    }
+
+    fn apply_proposal(
         &mut self,
         protocol_message: ProtocolMessage,
         backend: &EidMlsBackend,
     ) -> Result<(), EidError> {
+        let processed_message = self
+            .group
+            .process_message(&backend.mls_backend, protocol_message)
+            .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+        if let ProposalMessage(_) = processed_message.content() {
+            self.apply_processed_message(processed_message, None, backend)?;
+            Ok(())
+        } else {
+            Err(EidError::InvalidEvolvementError(
+                "Expected ProcessedMessage::ProposalMessage, got a different variant of a processed message".into(),
+            ))
+        }
+    }
+
+    fn merge_or_apply_commit(
+        &mut self,
+        protocol_message: ProtocolMessage,
+        signed_state: Option<&EidMlsSignedState>,
+        backend: &EidMlsBackend,
+    ) -> Result<Vec<MembershipEvent<EidMlsMember>>, EidError> {
         let processed_message_result = self
             .group
             .process_message(&backend.mls_backend, protocol_message);
 
         match processed_message_result {
             Ok(processed_message) => {
-                self.apply_processed_message(processed_message, backend)?;
-                Ok(())
+                self.apply_processed_message(processed_message, signed_state, backend)
             }
             Err(process_message_error) => {
                 if let ProcessMessageError::InvalidCommit(StageCommitError::OwnCommit) =
                     process_message_error
                 {
+                    let members: Vec<_> = self.group.members().collect();
+                    let own_leaf = Sender::Member(self.group.own_leaf_index());
+                    let sender = sender_member(&members, &own_leaf);
+                    if let Some(sender) = &sender {
+                        if self.is_expired(sender, None) {
+                            return Err(EidError::InvalidEvolvementError(
+                                "The invitation of the sender has expired".into(),
+                            ));
+                        }
+                    }
+                    let authenticated_data = self.pending_authenticated_data.clone();
+                    let cross_signature = commit_cross_signature(
+                        self.eid_id().as_slice(),
+                        authenticated_data.as_ref(),
+                        sender.as_ref(),
+                        self.signature_scheme(),
+                        backend,
+                    )?;
+                    let cross_signed_members = self.cross_signed_members();
+                    let change = match self.group.pending_commit() {
+                        Some(staged_commit) => staged_commit_change(staged_commit, &members),
+                        None => EidChange {
+                            added: vec![],
+                            removed: vec![],
+                            self_update: false,
+                        },
+                    };
                     self.group
                         .merge_pending_commit(&backend.mls_backend)
                         .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
-                    return Ok(());
+                    self.pending_authenticated_data = None;
+                    self.update_after_commit(&change, authenticated_data, cross_signature);
+                    return Ok(self.membership_events(change, &members, &cross_signed_members));
                 }
 
                 Err(EidError::InvalidEvolvementError(
@@ -110,15 +611,99 @@ impl EidMlsClientState {
         }
     }
 
-    fn get_leaf_nodes(&self) -> Vec<LeafNode> {
-        self.group
-            .export_ratchet_tree()
-            .iter()
-            .filter_map(|node| match node {
-                Some(Node::LeafNode(leaf_node)) => Some(LeafNode::from(leaf_node.clone())),
-                Some(Node::ParentNode(_)) | None => None,
-            })
-            .collect()
+    /// Get the [MembershipEvent]s of a commit that was just merged (see [membership_events]).
+    fn membership_events(
+        &self,
+        change: EidChange<EidMlsMember>,
+        members: &[MlsMember],
+        cross_signed_members: &[Vec<u8>],
+    ) -> Vec<MembershipEvent<EidMlsMember>> {
+        let members_after: Vec<_> = self.group.members().collect();
+        membership_events(
+            change,
+            members,
+            cross_signed_members,
+            &members_after,
+            &self.cross_signed_members(),
+        )
+    }
+
+    /// Remember the cross signature and the invitations of a commit that was just merged
+    /// (see [update_cross_signatures] and [update_invitations]).
+    fn update_after_commit(
+        &mut self,
+        change: &EidChange<EidMlsMember>,
+        authenticated_data: Option<EidMlsAuthenticatedData>,
+        cross_signature: Option<EidMlsCrossSignature>,
+    ) {
+        let members: Vec<_> = self.group.members().collect();
+        update_cross_signatures(&mut self.cross_signatures, cross_signature, &members);
+        let expiry = authenticated_data
+            .as_ref()
+            .and_then(EidMlsAuthenticatedData::invitation_expiry);
+        let pending_members = self.get_pending_members();
+        update_invitations(
+            &mut self.invitations,
+            &change.added,
+            expiry,
+            &pending_members,
+        );
+    }
+
+    /// Verify a commit before it is merged.
+    /// The invitation of the sender must not have expired, and the signed state the commit was received with, if any,
+    /// must hold the cross signatures and invitations the state has after merging the commit.
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidEvolvementError] if the commit is invalid.
+    fn verify_commit(
+        &self,
+        sender: Option<&EidMlsMember>,
+        change: &EidChange<EidMlsMember>,
+        authenticated_data: Option<&EidMlsAuthenticatedData>,
+        cross_signature: Option<&EidMlsCrossSignature>,
+        signed_state: Option<&EidMlsSignedState>,
+    ) -> Result<(), EidError> {
+        if let Some(sender) = sender {
+            if self.is_expired(sender, None) {
+                return Err(EidError::InvalidEvolvementError(
+                    "The invitation of the sender has expired".into(),
+                ));
+            }
+        }
+        if let Some(signed_state) = signed_state {
+            let expiry = authenticated_data.and_then(EidMlsAuthenticatedData::invitation_expiry);
+            signed_state.verify_matches(
+                self.epoch() + 1,
+                &cross_signatures_after_commit(&self.cross_signatures, change, cross_signature),
+                &invitations_after_commit(
+                    &self.invitations,
+                    self.get_pending_members(),
+                    change,
+                    expiry,
+                ),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Get the signature keys of all members that have cross-signed their membership.
+    fn cross_signed_members(&self) -> Vec<Vec<u8>> {
+        cross_signed_members(&self.cross_signatures)
+    }
+
+    /// Get the signature scheme of the group's ciphersuite, which members sign with.
+    pub(crate) fn signature_scheme(&self) -> SignatureScheme {
+        self.group.ciphersuite().signature_algorithm()
+    }
+
+    /// True if the invitation of the member has expired in the current epoch.
+    fn is_expired(&self, member: &EidMlsMember, now: Option<u64>) -> bool {
+        is_invitation_expired(
+            &self.invitations,
+            member.credential.signature_key.as_slice(),
+            self.epoch(),
+            now,
+        )
     }
 
     /// True if the member has cross-signed their addition to the group.
@@ -129,14 +714,30 @@ impl EidMlsClientState {
     ///
     /// returns: [Result]<bool, [EidError]>
     fn has_member(&self, member: &MlsMember) -> Result<bool, EidError> {
-        let leaf_nodes = self.get_leaf_nodes();
-        let leaf_node: &LeafNode = leaf_nodes
-            .iter()
-            .find(|&node| node.signature_key().as_slice().to_vec() == member.signature_key)
+        self.group
+            .members()
+            .find(|node| node.signature_key == member.signature_key)
             .ok_or_else(|| {
                 EidError::InvalidMemberError("Member index doesn't have a matching node".into())
             })?;
-        let parent_hash = leaf_node.parent_hash();
-        Ok(parent_hash.is_some())
+        Ok(self
+            .cross_signatures
+            .iter()
+            .any(|cross_signature| cross_signature.signature_key == member.signature_key))
     }
 }
+
+/// # EID MLS Exported Client State
+/// Persistable state of an [EidMlsClient](crate::eid_mls_client::EidMlsClient).
+/// Holds the serialized [MlsGroup], the cross signatures of members, the expiring invitations, the client's serialized signature key pair
+/// and the key store entries of the client's leaf that are needed to process future evolvements.
+/// Entries of other clients that share the backend aren't included.
+/// Since it holds secret keys, the exported state must be stored as carefully as the keys themselves.
+#[derive(Debug, Clone, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidMlsExportedClientState {
+    pub(crate) group: Vec<u8>,
+    pub(crate) cross_signatures: Vec<EidMlsCrossSignature>,
+    pub(crate) invitations: Vec<EidMlsInvitation>,
+    pub(crate) key_pair: Vec<u8>,
+    pub(crate) key_store: Vec<EidMlsKeyStoreEntry>,
+}
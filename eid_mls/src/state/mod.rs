@@ -1,2 +1,702 @@
+use openmls::prelude::{
+    Credential, CredentialWithKey, Member as MlsMember, ProcessMessageError, ProcessedMessage,
+    ProcessedMessageContent, Proposal, QueuedProposal, Sender, StagedCommit, ValidationError,
+};
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::crypto::OpenMlsCrypto;
+use openmls_traits::signatures::Signer;
+use openmls_traits::types::SignatureScheme;
+use openmls_traits::OpenMlsCryptoProvider;
+use tls_codec::{Deserialize, Serialize, TlsDeserialize, TlsSerialize, TlsSize};
+
+use eid_traits::evolvement::{EvolvementKind, EvolvementSummary, EvolvementVerdict};
+use eid_traits::types::{
+    ChallengeResponse, EidChange, EidError, EidId, InvitationExpiry, MembershipEvent,
+};
+
+use crate::eid_mls_backend::EidMlsBackend;
+use crate::eid_mls_member::EidMlsMember;
+
 pub mod client_state;
 pub mod transcript_state;
+
+/// # EID MLS Invitation
+/// The expiry of the invitation of a pending member, identified by its signature key.
+/// The expiry is sent as authenticated data of the commit that adds the member.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidMlsInvitation {
+    pub(crate) signature_key: Vec<u8>,
+    pub(crate) expiry: InvitationExpiry,
+}
+
+/// # EID MLS Signed State
+/// The cross signatures of the members and the invitations of the pending members of an EID in an epoch,
+/// signed by a member of the EID.
+/// They aren't part of the MLS group state, so evolvements that invite members and exported transcript states
+/// carry them in this form. A client that joins or a transcript that is imported only accepts them if a member signed them
+/// for the epoch it starts in. Existing members reject a commit whose signed state differs from their own view,
+/// so that invited members see the same cross-signed members and enforce the same expiries.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidMlsSignedState {
+    pub(crate) epoch: u64,
+    pub(crate) cross_signatures: Vec<EidMlsCrossSignature>,
+    pub(crate) invitations: Vec<EidMlsInvitation>,
+    pub(crate) signature_key: Vec<u8>,
+    pub(crate) signature: Vec<u8>,
+}
+
+/// The content a member signs to issue an [EidMlsSignedState].
+#[derive(TlsSerialize, TlsSize)]
+struct SignedStateContent {
+    label: Vec<u8>,
+    eid_id: Vec<u8>,
+    epoch: u64,
+    cross_signatures: Vec<EidMlsCrossSignature>,
+    invitations: Vec<EidMlsInvitation>,
+}
+
+impl EidMlsSignedState {
+    /// Get the content a member signs. It is labeled, so that it can't be confused with signatures for other purposes.
+    fn signed_content(
+        eid_id: &[u8],
+        epoch: u64,
+        cross_signatures: &[EidMlsCrossSignature],
+        invitations: &[EidMlsInvitation],
+    ) -> Result<Vec<u8>, EidError> {
+        SignedStateContent {
+            label: b"EID state".to_vec(),
+            eid_id: eid_id.to_vec(),
+            epoch,
+            cross_signatures: cross_signatures.to_vec(),
+            invitations: invitations.to_vec(),
+        }
+        .tls_serialize_detached()
+        .map_err(|e| EidError::SerializationError(e.to_string()))
+    }
+
+    /// Sign the `cross_signatures` and `invitations` of the EID with identifier `eid_id` in `epoch`.
+    pub(crate) fn sign(
+        eid_id: &[u8],
+        epoch: u64,
+        cross_signatures: Vec<EidMlsCrossSignature>,
+        invitations: Vec<EidMlsInvitation>,
+        key_pair: &SignatureKeyPair,
+    ) -> Result<Self, EidError> {
+        let content = Self::signed_content(eid_id, epoch, &cross_signatures, &invitations)?;
+        let signature = key_pair
+            .sign(&content)
+            .map_err(|e| EidError::SerializationError(e.to_string()))?;
+        Ok(Self {
+            epoch,
+            cross_signatures,
+            invitations,
+            signature_key: key_pair.public().to_vec(),
+            signature,
+        })
+    }
+
+    /// Verify that one of the `members` of the EID with identifier `eid_id` signed the state for `epoch`
+    /// and that every cross signature it holds is valid (see [verify_cross_signatures]).
+    ///
+    /// # Arguments
+    ///
+    /// * `eid_id`: The identifier of the EID
+    /// * `epoch`: The epoch of the group the state was received with
+    /// * `members`: The members of the group
+    /// * `signature_scheme`: The signature scheme of the group's ciphersuite
+    /// * `backend`: The [EidMlsBackend]
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidInvitationError] if the state isn't signed by a member for `epoch`
+    /// or holds an invalid cross signature.
+    pub(crate) fn verify(
+        &self,
+        eid_id: &[u8],
+        epoch: u64,
+        members: &[MlsMember],
+        signature_scheme: SignatureScheme,
+        backend: &EidMlsBackend,
+    ) -> Result<(), EidError> {
+        if self.epoch != epoch {
+            return Err(EidError::InvalidInvitationError(format!(
+                "The state is signed for epoch {}, expected epoch {epoch}",
+                self.epoch
+            )));
+        }
+        if !members
+            .iter()
+            .any(|member| member.signature_key == self.signature_key)
+        {
+            return Err(EidError::InvalidInvitationError(
+                "The state isn't signed by a member".into(),
+            ));
+        }
+        let content = Self::signed_content(
+            eid_id,
+            self.epoch,
+            &self.cross_signatures,
+            &self.invitations,
+        )?;
+        backend
+            .mls_backend
+            .crypto()
+            .verify_signature(
+                signature_scheme,
+                &content,
+                &self.signature_key,
+                &self.signature,
+            )
+            .map_err(|_| EidError::InvalidInvitationError("Invalid state signature".into()))?;
+        verify_cross_signatures(
+            eid_id,
+            &self.cross_signatures,
+            members,
+            signature_scheme,
+            backend,
+        )
+        .map_err(|e| EidError::InvalidInvitationError(e.to_string()))
+    }
+
+    /// Verify that the state is the state an existing member has in `epoch`, e.g. after merging the commit it was sent with.
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidEvolvementError] if the states differ.
+    pub(crate) fn verify_matches(
+        &self,
+        epoch: u64,
+        cross_signatures: &[EidMlsCrossSignature],
+        invitations: &[EidMlsInvitation],
+    ) -> Result<(), EidError> {
+        if self.epoch != epoch
+            || self.cross_signatures != cross_signatures
+            || self.invitations != invitations
+        {
+            return Err(EidError::InvalidEvolvementError(
+                "The signed state doesn't match the state after the commit".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// # EID MLS Cross Signature
+/// The signature of a member over the EID identifier and its own credential (see [cross_sign_payload]).
+/// It proves that the member consented to joining the EID and can be verified by anyone who knows the member's leaf.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidMlsCrossSignature {
+    pub(crate) signature_key: Vec<u8>,
+    pub(crate) signature: Vec<u8>,
+}
+
+/// # EID MLS Authenticated Data
+/// Data that is sent as authenticated data of a commit, which is read by all clients and transcripts.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+#[repr(u8)]
+pub(crate) enum EidMlsAuthenticatedData {
+    /// The expiry of the invitations of the members the commit adds.
+    InvitationExpiry(InvitationExpiry),
+    /// The cross signature of the member that created the commit.
+    CrossSignature(Vec<u8>),
+}
+
+impl EidMlsAuthenticatedData {
+    /// Read the authenticated data of a commit.
+    ///
+    /// returns: [Result]<[Option]<[EidMlsAuthenticatedData]>, [EidError]> [None] if the commit has no authenticated data.
+    pub(crate) fn from_aad(aad: &[u8]) -> Result<Option<Self>, EidError> {
+        if aad.is_empty() {
+            return Ok(None);
+        }
+        Self::tls_deserialize(&mut &aad[..])
+            .map(Some)
+            .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))
+    }
+
+    pub(crate) fn to_aad(&self) -> Result<Vec<u8>, EidError> {
+        self.tls_serialize_detached()
+            .map_err(|e| EidError::SerializationError(e.to_string()))
+    }
+
+    pub(crate) fn invitation_expiry(&self) -> Option<InvitationExpiry> {
+        match self {
+            Self::InvitationExpiry(expiry) => Some(*expiry),
+            Self::CrossSignature(_) => None,
+        }
+    }
+
+    pub(crate) fn cross_signature(&self) -> Option<&[u8]> {
+        match self {
+            Self::CrossSignature(signature) => Some(signature.as_slice()),
+            Self::InvitationExpiry(_) => None,
+        }
+    }
+}
+
+/// The content a member signs to cross-sign its membership: the EID identifier and the member's credential.
+#[derive(TlsSerialize, TlsSize)]
+struct CrossSignContent {
+    label: Vec<u8>,
+    eid_id: Vec<u8>,
+    credential: Credential,
+    signature_key: Vec<u8>,
+}
+
+/// Get the payload a member signs to cross-sign its membership in the EID with identifier `eid_id`.
+/// It is labeled, since the same key signs challenges and the caller may choose `eid_id`.
+pub(crate) fn cross_sign_payload(
+    eid_id: &[u8],
+    credential: &CredentialWithKey,
+) -> Result<Vec<u8>, EidError> {
+    CrossSignContent {
+        label: b"EID cross-sign".to_vec(),
+        eid_id: eid_id.to_vec(),
+        credential: credential.credential.clone(),
+        signature_key: credential.signature_key.as_slice().to_vec(),
+    }
+    .tls_serialize_detached()
+    .map_err(|e| EidError::SerializationError(e.to_string()))
+}
+
+/// Verify the cross signature of a member of the EID with identifier `eid_id`,
+/// using the `signature_scheme` of the group's ciphersuite.
+///
+/// returns: [Result]<[EidMlsCrossSignature], [EidError]> [EidError::InvalidEvolvementError] if the signature is invalid.
+pub(crate) fn verify_cross_signature(
+    eid_id: &[u8],
+    member: &EidMlsMember,
+    signature: &[u8],
+    signature_scheme: SignatureScheme,
+    backend: &EidMlsBackend,
+) -> Result<EidMlsCrossSignature, EidError> {
+    let payload = cross_sign_payload(eid_id, &member.credential)?;
+    let signature_key = member.credential.signature_key.as_slice();
+    backend
+        .mls_backend
+        .crypto()
+        .verify_signature(signature_scheme, &payload, signature_key, signature)
+        .map_err(|_| EidError::InvalidEvolvementError("Invalid cross signature".into()))?;
+    Ok(EidMlsCrossSignature {
+        signature_key: signature_key.to_vec(),
+        signature: signature.to_vec(),
+    })
+}
+
+/// Verify the cross signature a commit carries in its authenticated data, if any.
+///
+/// returns: [Result]<[Option]<[EidMlsCrossSignature]>, [EidError]> [None] if the commit doesn't cross-sign.
+pub(crate) fn commit_cross_signature(
+    eid_id: &[u8],
+    authenticated_data: Option<&EidMlsAuthenticatedData>,
+    sender: Option<&EidMlsMember>,
+    signature_scheme: SignatureScheme,
+    backend: &EidMlsBackend,
+) -> Result<Option<EidMlsCrossSignature>, EidError> {
+    match authenticated_data.and_then(EidMlsAuthenticatedData::cross_signature) {
+        Some(signature) => {
+            let sender = sender.ok_or_else(|| {
+                EidError::InvalidEvolvementError("Cross signature without a sender".into())
+            })?;
+            verify_cross_signature(eid_id, sender, signature, signature_scheme, backend).map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Verify cross signatures that were received together with a group, e.g. when joining or importing a transcript.
+/// Every cross signature has to belong to a member of the group.
+pub(crate) fn verify_cross_signatures(
+    eid_id: &[u8],
+    cross_signatures: &[EidMlsCrossSignature],
+    members: &[MlsMember],
+    signature_scheme: SignatureScheme,
+    backend: &EidMlsBackend,
+) -> Result<(), EidError> {
+    for cross_signature in cross_signatures {
+        let member = members
+            .iter()
+            .find(|member| member.signature_key == cross_signature.signature_key)
+            .cloned()
+            .map(EidMlsMember::from_existing)
+            .ok_or_else(|| {
+                EidError::InvalidMemberError("Cross signature of an unknown member".into())
+            })?;
+        verify_cross_signature(
+            eid_id,
+            &member,
+            &cross_signature.signature,
+            signature_scheme,
+            backend,
+        )?;
+    }
+    Ok(())
+}
+
+/// Remember the cross signature of the sender of a merged commit and forget cross signatures of removed members.
+///
+/// # Arguments
+///
+/// * `cross_signatures`: The cross signatures of the state
+/// * `cross_signature`: The verified cross signature the commit carried, if any
+/// * `members`: The members of the group after the commit was merged
+pub(crate) fn update_cross_signatures(
+    cross_signatures: &mut Vec<EidMlsCrossSignature>,
+    cross_signature: Option<EidMlsCrossSignature>,
+    members: &[MlsMember],
+) {
+    if let Some(cross_signature) = cross_signature {
+        cross_signatures.retain(|known| known.signature_key != cross_signature.signature_key);
+        cross_signatures.push(cross_signature);
+    }
+    cross_signatures.retain(|cross_signature| {
+        members
+            .iter()
+            .any(|member| member.signature_key == cross_signature.signature_key)
+    });
+    // Every client and transcript keeps the cross signatures in the same order, so that signed states can be compared
+    cross_signatures.sort_by(|a, b| a.signature_key.cmp(&b.signature_key));
+}
+
+/// Get the cross signatures of a state after a commit is merged, before merging it (see [update_cross_signatures]).
+///
+/// # Arguments
+///
+/// * `cross_signatures`: The cross signatures of the state
+/// * `change`: The change of the commit
+/// * `cross_signature`: The verified cross signature the commit carries, if any
+///
+/// returns: [Vec]<[EidMlsCrossSignature]>
+pub(crate) fn cross_signatures_after_commit(
+    cross_signatures: &[EidMlsCrossSignature],
+    change: &EidChange<EidMlsMember>,
+    cross_signature: Option<&EidMlsCrossSignature>,
+) -> Vec<EidMlsCrossSignature> {
+    let mut cross_signatures = cross_signatures.to_vec();
+    if let Some(cross_signature) = cross_signature {
+        cross_signatures.retain(|known| known.signature_key != cross_signature.signature_key);
+        cross_signatures.push(cross_signature.clone());
+    }
+    cross_signatures.retain(|cross_signature| {
+        !change.removed.iter().any(|member| {
+            member.credential.signature_key.as_slice() == cross_signature.signature_key.as_slice()
+        })
+    });
+    cross_signatures.sort_by(|a, b| a.signature_key.cmp(&b.signature_key));
+    cross_signatures
+}
+
+/// Verify that a [ChallengeResponse] was signed by one of the `members` of the EID with identifier `eid_id`.
+///
+/// returns: [Result]<[EidMlsMember], [EidError]> The member that signed the challenge.
+/// [EidError::InvalidChallengeResponseError] if the signer isn't one of the members or the signature is invalid.
+pub(crate) fn verify_challenge_response(
+    eid_id: &EidId,
+    members: Vec<EidMlsMember>,
+    nonce: &[u8],
+    response: &ChallengeResponse,
+    signature_scheme: SignatureScheme,
+    backend: &EidMlsBackend,
+) -> Result<EidMlsMember, EidError> {
+    let member = members
+        .into_iter()
+        .find(|member| member.credential.signature_key.as_slice() == response.signature_key)
+        .ok_or_else(|| {
+            EidError::InvalidChallengeResponseError("Signer is not a member of the EID".into())
+        })?;
+    let content = ChallengeResponse::signed_content(eid_id, nonce)?;
+    backend
+        .mls_backend
+        .crypto()
+        .verify_signature(
+            signature_scheme,
+            &content,
+            &response.signature_key,
+            &response.signature,
+        )
+        .map_err(|_| EidError::InvalidChallengeResponseError("Invalid signature".into()))?;
+    Ok(member)
+}
+
+/// Get the signature keys of all members that have cross-signed their membership.
+pub(crate) fn cross_signed_members(cross_signatures: &[EidMlsCrossSignature]) -> Vec<Vec<u8>> {
+    cross_signatures
+        .iter()
+        .map(|cross_signature| cross_signature.signature_key.clone())
+        .collect()
+}
+
+/// Remember the invitations of the `added` members if they expire
+/// and forget invitations of members that cross-signed or were removed.
+///
+/// # Arguments
+///
+/// * `invitations`: The invitations of the state
+/// * `added`: The members a commit added
+/// * `expiry`: The expiry of the invitations of the added members
+/// * `pending_members`: The pending members after the commit was merged
+pub(crate) fn update_invitations(
+    invitations: &mut Vec<EidMlsInvitation>,
+    added: &[EidMlsMember],
+    expiry: Option<InvitationExpiry>,
+    pending_members: &[EidMlsMember],
+) {
+    if let Some(expiry) = expiry {
+        invitations.extend(added.iter().map(|member| EidMlsInvitation {
+            signature_key: member.credential.signature_key.as_slice().to_vec(),
+            expiry,
+        }));
+    }
+    invitations.retain(|invitation| {
+        pending_members.iter().any(|member| {
+            member.credential.signature_key.as_slice() == invitation.signature_key.as_slice()
+        })
+    });
+    // Every client and transcript keeps the invitations in the same order, so that signed states can be compared
+    invitations.sort_by(|a, b| a.signature_key.cmp(&b.signature_key));
+}
+
+/// Get the invitations of a state after a commit is merged, before merging it (see [update_invitations]).
+///
+/// # Arguments
+///
+/// * `invitations`: The invitations of the state
+/// * `pending_members`: The pending members of the state
+/// * `change`: The change of the commit
+/// * `expiry`: The expiry of the invitations of the members the commit adds
+///
+/// returns: [Vec]<[EidMlsInvitation]>
+pub(crate) fn invitations_after_commit(
+    invitations: &[EidMlsInvitation],
+    mut pending_members: Vec<EidMlsMember>,
+    change: &EidChange<EidMlsMember>,
+    expiry: Option<InvitationExpiry>,
+) -> Vec<EidMlsInvitation> {
+    pending_members.retain(|member| !change.removed.contains(member));
+    pending_members.extend(change.added.iter().cloned());
+    let mut invitations = invitations.to_vec();
+    update_invitations(&mut invitations, &change.added, expiry, &pending_members);
+    invitations
+}
+
+/// True if the member with `signature_key` has an invitation that has expired.
+pub(crate) fn is_invitation_expired(
+    invitations: &[EidMlsInvitation],
+    signature_key: &[u8],
+    epoch: u64,
+    now: Option<u64>,
+) -> bool {
+    invitations.iter().any(|invitation| {
+        invitation.signature_key.as_slice() == signature_key
+            && invitation.expiry.is_expired(epoch, now)
+    })
+}
+
+/// Classify a message of an evolvement that was processed successfully.
+/// Only members may evolve an EID, and only proposals and commits evolve it.
+pub(crate) fn message_verdict(message: &ProcessedMessage) -> EvolvementVerdict {
+    if !matches!(message.sender(), Sender::Member(_)) {
+        return EvolvementVerdict::WrongSender;
+    }
+    match message.content() {
+        ProcessedMessageContent::ProposalMessage(_)
+        | ProcessedMessageContent::StagedCommitMessage(_) => EvolvementVerdict::Valid,
+        ProcessedMessageContent::ApplicationMessage(_)
+        | ProcessedMessageContent::ExternalJoinProposalMessage(_) => EvolvementVerdict::NotACommit,
+    }
+}
+
+/// Classify an error that occurred while processing a message of an evolvement.
+pub(crate) fn error_verdict(error: &ProcessMessageError) -> EvolvementVerdict {
+    match error {
+        ProcessMessageError::InvalidSignature => EvolvementVerdict::BadSignature,
+        ProcessMessageError::ValidationError(ValidationError::UnknownMember) => {
+            EvolvementVerdict::WrongSender
+        }
+        error => EvolvementVerdict::Invalid(error.to_string()),
+    }
+}
+
+/// Find the member that sent a message among the members of the group before the message is applied.
+pub(crate) fn sender_member(members: &[MlsMember], sender: &Sender) -> Option<EidMlsMember> {
+    match sender {
+        Sender::Member(index) => members
+            .iter()
+            .find(|member| member.index == *index)
+            .cloned()
+            .map(EidMlsMember::from_existing),
+        _ => None,
+    }
+}
+
+/// Get the members a staged commit adds and removes.
+///
+/// # Arguments
+///
+/// * `staged_commit`: The [StagedCommit]
+/// * `members`: The members of the group before the commit is merged
+///
+/// returns: [EidChange]<[EidMlsMember]>
+pub(crate) fn staged_commit_change(
+    staged_commit: &StagedCommit,
+    members: &[MlsMember],
+) -> EidChange<EidMlsMember> {
+    let added: Vec<_> = staged_commit
+        .add_proposals()
+        .map(|proposal| {
+            EidMlsMember::from_key_package(proposal.add_proposal().key_package().clone())
+        })
+        .collect();
+    let removed: Vec<_> = staged_commit
+        .remove_proposals()
+        .filter_map(|proposal| {
+            let index = proposal.remove_proposal().removed();
+            members
+                .iter()
+                .find(|member| member.index == index)
+                .cloned()
+                .map(EidMlsMember::from_existing)
+        })
+        .collect();
+    let self_update =
+        added.is_empty() && removed.is_empty() && staged_commit.update_proposals().next().is_none();
+    EidChange {
+        added,
+        removed,
+        self_update,
+    }
+}
+
+/// Summarize a staged commit.
+///
+/// # Arguments
+///
+/// * `staged_commit`: The [StagedCommit]
+/// * `members`: The members of the group before the commit is merged
+/// * `sender`: The member that created the commit
+/// * `cross_signs`: True if the commit carries the cross signature of the sender
+/// * `epoch`: The epoch of the group after the commit is merged
+///
+/// returns: [EvolvementSummary]<[EidMlsMember]>
+pub(crate) fn commit_summary(
+    staged_commit: &StagedCommit,
+    members: &[MlsMember],
+    sender: Option<EidMlsMember>,
+    cross_signs: bool,
+    epoch: u64,
+) -> EvolvementSummary<EidMlsMember> {
+    let change = staged_commit_change(staged_commit, members);
+    EvolvementSummary {
+        kind: EvolvementKind::of_commit(
+            !change.added.is_empty(),
+            !change.removed.is_empty(),
+            cross_signs,
+        ),
+        sender,
+        added: change.added,
+        removed: change.removed,
+        epoch,
+    }
+}
+
+/// Summarize a proposal, which doesn't change the epoch of the group.
+pub(crate) fn proposal_summary(
+    queued_proposal: &QueuedProposal,
+    members: &[MlsMember],
+    sender: Option<EidMlsMember>,
+    epoch: u64,
+) -> EvolvementSummary<EidMlsMember> {
+    let (added, removed) = match queued_proposal.proposal() {
+        Proposal::Add(add_proposal) => (
+            vec![EidMlsMember::from_key_package(
+                add_proposal.key_package().clone(),
+            )],
+            vec![],
+        ),
+        Proposal::Remove(remove_proposal) => (
+            vec![],
+            members
+                .iter()
+                .find(|member| member.index == remove_proposal.removed())
+                .cloned()
+                .map(EidMlsMember::from_existing)
+                .into_iter()
+                .collect(),
+        ),
+        _ => (vec![], vec![]),
+    };
+    EvolvementSummary {
+        kind: EvolvementKind::Proposal,
+        sender,
+        added,
+        removed,
+        epoch,
+    }
+}
+
+/// Get the [MembershipEvent]s of a merged commit.
+/// Added and removed members are taken from the commit, cross-signatures from the states and key updates from the leaves of the group.
+///
+/// # Arguments
+///
+/// * `change`: The members the commit added and removed (see [staged_commit_change])
+/// * `members_before`: The members of the group before the commit was merged
+/// * `cross_signed_before`: Signature keys of the cross-signed members before the commit was merged
+/// * `members_after`: The members of the group after the commit was merged
+/// * `cross_signed_after`: Signature keys of the cross-signed members after the commit was merged
+///
+/// returns: [Vec]<[MembershipEvent]<[EidMlsMember]>>
+pub(crate) fn membership_events(
+    change: EidChange<EidMlsMember>,
+    members_before: &[MlsMember],
+    cross_signed_before: &[Vec<u8>],
+    members_after: &[MlsMember],
+    cross_signed_after: &[Vec<u8>],
+) -> Vec<MembershipEvent<EidMlsMember>> {
+    let mut events: Vec<_> = change
+        .removed
+        .into_iter()
+        .map(MembershipEvent::MemberRemoved)
+        .chain(change.added.into_iter().map(MembershipEvent::MemberAdded))
+        .collect();
+    for member in members_after {
+        let previous = members_before
+            .iter()
+            .find(|previous| previous.signature_key == member.signature_key);
+        if let Some(previous) = previous {
+            if !cross_signed_before.contains(&member.signature_key)
+                && cross_signed_after.contains(&member.signature_key)
+            {
+                events.push(MembershipEvent::MemberCrossSigned(
+                    EidMlsMember::from_existing(member.clone()),
+                ));
+            } else if previous.encryption_key != member.encryption_key {
+                events.push(MembershipEvent::MemberUpdatedKey(
+                    EidMlsMember::from_existing(member.clone()),
+                ));
+            }
+        }
+    }
+    events
+}
+
+/// Serialize a field with [tls_codec] in [serde] formats, e.g. the cross signatures and invitations of a transcript state,
+/// which derives its [serde] serialization to include the group.
+pub(crate) mod tls_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use tls_codec::{Deserialize as TlsDeserialize, Serialize as TlsSerialize};
+
+    pub(crate) fn serialize<T: TlsSerialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let bytes = value
+            .tls_serialize_detached()
+            .map_err(|e| serde::ser::Error::custom(e.to_string()))?;
+        serializer.serialize_bytes(&bytes)
+    }
+
+    pub(crate) fn deserialize<'de, T: TlsDeserialize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        T::tls_deserialize(&mut bytes.as_slice())
+            .map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
@@ -2,26 +2,40 @@ use std::io::{Read, Write};
 
 use openmls::group::PublicGroup;
 use openmls::prelude::{
-    LeafNode, Member as MlsMember, MlsMessageIn, MlsMessageInBody, MlsMessageOut, Node,
-    ProcessedMessageContent, ProposalStore, ProtocolMessage,
+    Member as MlsMember, MlsMessageIn, MlsMessageInBody, MlsMessageOut, Node,
+    ProcessedMessageContent, ProposalStore, ProtocolMessage, SignatureScheme,
 };
 use serde;
 use serde_json;
 use tls_codec::{Deserialize, Error as TlsError, Serialize, Size};
 
+use eid_traits::evolvement::{EvolvementSummary, EvolvementVerdict};
 use eid_traits::state::EidState;
 use eid_traits::transcript::EidExportedTranscriptState;
-use eid_traits::types::EidError;
+use eid_traits::types::{ChallengeResponse, EidChange, EidError, EidId, MembershipEvent};
 
 use crate::eid_mls_backend::EidMlsBackend;
 use crate::eid_mls_evolvement::EidMlsEvolvement;
 use crate::eid_mls_member::EidMlsMember;
+use crate::state::{
+    commit_cross_signature, commit_summary, cross_signatures_after_commit, cross_signed_members,
+    error_verdict, invitations_after_commit, is_invitation_expired, membership_events,
+    message_verdict, proposal_summary, sender_member, staged_commit_change,
+    update_cross_signatures, update_invitations, verify_challenge_response,
+    EidMlsAuthenticatedData, EidMlsCrossSignature, EidMlsInvitation, EidMlsSignedState,
+};
 
 /// # EidTranscriptState
 /// Implementation of [EidState] for the Transcript using [openmls] wrapping a [PublicGroup].
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct EidMlsTranscriptState {
     pub(crate) group: PublicGroup,
+    /// Cross signatures of all members that have cross-signed their membership.
+    #[serde(with = "crate::state::tls_serde")]
+    pub(crate) cross_signatures: Vec<EidMlsCrossSignature>,
+    /// Invitations of pending members that expire.
+    #[serde(with = "crate::state::tls_serde")]
+    pub(crate) invitations: Vec<EidMlsInvitation>,
 }
 
 impl EidState for EidMlsTranscriptState {
@@ -33,41 +47,213 @@ impl EidState for EidMlsTranscriptState {
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError> {
-        if let EidMlsEvolvement::IN { message, .. } = evolvement {
-            let body = message.extract();
-            if let MlsMessageInBody::PublicMessage(public_message) = body {
-                let protocol_message = ProtocolMessage::PublicMessage(public_message);
-                let processed_message = self
-                    .group
-                    .process_message(&backend.mls_backend, protocol_message)
-                    .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
-                match processed_message.into_content() {
-                    ProcessedMessageContent::ApplicationMessage(_)
-                    | ProcessedMessageContent::ProposalMessage(_)
-                    | ProcessedMessageContent::ExternalJoinProposalMessage(_) => {
-                        return Err(EidError::InvalidEvolvementError(
-                            "Unexpected message type.".into(),
-                        ))
-                    }
-                    ProcessedMessageContent::StagedCommitMessage(staged_commit) => {
-                        self.group.merge_commit(*staged_commit);
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> {
+        self.verify_eid(&evolvement)?;
+        self.verify_epoch(&evolvement)?;
+        // Proposals are added to the group's proposal store before the commit is verified,
+        // so we work on a copy that only replaces the state once the evolvement is applied
+        let mut state = self.clone_serde()?;
+        let events = state.apply_messages(evolvement, backend)?;
+        *self = state;
+        Ok(events)
+    }
+
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict {
+        if let Err(error) = self
+            .verify_eid(evolvement)
+            .and_then(|_| self.verify_epoch(evolvement))
+        {
+            return error.into();
+        }
+        let (message, proposals, signed_state) = match evolvement {
+            EidMlsEvolvement::IN {
+                message,
+                proposals,
+                signed_state,
+                ..
+            } => (message, proposals, signed_state),
+            EidMlsEvolvement::OUT { .. } => {
+                return EvolvementVerdict::Invalid(
+                    "Expected EidMlsEvolvement::IN, got ::OUT".into(),
+                )
+            }
+        };
+        // Proposals are added to the group's proposal store, so we work on a copy
+        let mut state = match self.clone_serde() {
+            Ok(state) => state,
+            Err(error) => return error.into(),
+        };
+        for proposal in proposals {
+            let protocol_message = match Self::protocol_message(proposal.clone()) {
+                Ok(protocol_message) => protocol_message,
+                Err(error) => return error.into(),
+            };
+            let processed_message = match state
+                .group
+                .process_message(&backend.mls_backend, protocol_message)
+            {
+                Ok(processed_message) => processed_message,
+                Err(error) => return error_verdict(&error),
+            };
+            let verdict = message_verdict(&processed_message);
+            if !verdict.is_valid() {
+                return verdict;
+            }
+            match processed_message.into_content() {
+                ProcessedMessageContent::ProposalMessage(queued_proposal) => {
+                    state.group.add_proposal(*queued_proposal);
+                }
+                _ => return EvolvementVerdict::Invalid("Expected a proposal.".into()),
+            }
+        }
+        let protocol_message = match Self::protocol_message(message.clone()) {
+            Ok(protocol_message) => protocol_message,
+            Err(error) => return error.into(),
+        };
+        match state
+            .group
+            .process_message(&backend.mls_backend, protocol_message)
+        {
+            Ok(processed_message) => {
+                let verdict = message_verdict(&processed_message);
+                if !verdict.is_valid() {
+                    return verdict;
+                }
+                if let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+                    processed_message.content()
+                {
+                    let members: Vec<_> = self.group.members().collect();
+                    let sender = sender_member(&members, processed_message.sender());
+                    let authenticated_data =
+                        match EidMlsAuthenticatedData::from_aad(processed_message.aad()) {
+                            Ok(authenticated_data) => authenticated_data,
+                            Err(error) => return EvolvementVerdict::Invalid(error.to_string()),
+                        };
+                    // The same checks as when the commit is applied
+                    let change = staged_commit_change(staged_commit, &members);
+                    let cross_signature = match commit_cross_signature(
+                        self.eid_id().as_slice(),
+                        authenticated_data.as_ref(),
+                        sender.as_ref(),
+                        self.signature_scheme(),
+                        backend,
+                    ) {
+                        Ok(cross_signature) => cross_signature,
+                        Err(_) => return EvolvementVerdict::BadSignature,
+                    };
+                    if let Err(error) = self.verify_commit(
+                        sender.as_ref(),
+                        &change,
+                        authenticated_data.as_ref(),
+                        cross_signature.as_ref(),
+                        signed_state.as_ref(),
+                    ) {
+                        return error.into();
                     }
-                };
-                Ok(())
-            } else {
-                Err(EidError::InvalidEvolvementError(format!(
-                    "Expected PublicMessage, got {:?}",
-                    body
+                }
+                verdict
+            }
+            Err(error) => error_verdict(&error),
+        }
+    }
+
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError> {
+        self.verify_eid(evolvement)?;
+        self.verify_epoch(evolvement)?;
+        let (message, proposals) = match evolvement {
+            EidMlsEvolvement::IN {
+                message, proposals, ..
+            } => (message, proposals),
+            EidMlsEvolvement::OUT { .. } => {
+                return Err(EidError::InvalidEvolvementError(String::from(
+                    "Expected EidMlsEvolvement::IN, got ::OUT",
                 )))
             }
-        } else {
-            Err(EidError::InvalidEvolvementError(String::from(
-                "Expected EidMlsEvolvement::IN, got ::OUT",
-            )))
+        };
+        let members: Vec<_> = self.group.members().collect();
+        // Proposals are added to the group's proposal store, so we work on a copy
+        let mut state = self.clone_serde()?;
+        for proposal in proposals {
+            let protocol_message = Self::protocol_message(proposal.clone())?;
+            let processed_message = state
+                .group
+                .process_message(&backend.mls_backend, protocol_message)
+                .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+            if let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+                processed_message.into_content()
+            {
+                state.group.add_proposal(*queued_proposal);
+            } else {
+                return Err(EidError::InvalidEvolvementError(
+                    "Expected a proposal.".into(),
+                ));
+            }
+        }
+        let protocol_message = Self::protocol_message(message.clone())?;
+        let processed_message = state
+            .group
+            .process_message(&backend.mls_backend, protocol_message)
+            .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+        let sender = sender_member(&members, processed_message.sender());
+        let authenticated_data = EidMlsAuthenticatedData::from_aad(processed_message.aad())?;
+        let cross_signs = matches!(
+            authenticated_data,
+            Some(EidMlsAuthenticatedData::CrossSignature(_))
+        );
+        match processed_message.content() {
+            ProcessedMessageContent::StagedCommitMessage(staged_commit) => Ok(commit_summary(
+                staged_commit,
+                &members,
+                sender,
+                cross_signs,
+                self.epoch() + 1,
+            )),
+            ProcessedMessageContent::ProposalMessage(queued_proposal) => Ok(proposal_summary(
+                queued_proposal,
+                &members,
+                sender,
+                self.epoch(),
+            )),
+            ProcessedMessageContent::ApplicationMessage(_)
+            | ProcessedMessageContent::ExternalJoinProposalMessage(_) => Err(
+                EidError::InvalidEvolvementError("Unexpected message type.".into()),
+            ),
         }
     }
 
+    /// The group id is the [EidId], which members sign when cross-signing their membership.
+    fn eid_id(&self) -> EidId {
+        EidId::new(self.group.group_context().group_id().as_slice().to_vec())
+    }
+
+    fn epoch(&self) -> u64 {
+        self.group.group_context().epoch().as_u64()
+    }
+
+    fn verify_challenge(
+        &self,
+        nonce: &[u8],
+        response: &ChallengeResponse,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError> {
+        verify_challenge_response(
+            &self.eid_id(),
+            self.get_members(),
+            nonce,
+            response,
+            self.signature_scheme(),
+            backend,
+        )
+    }
+
     fn get_members(&self) -> Vec<Self::MemberProvider> {
         self.group
             .members()
@@ -75,43 +261,228 @@ impl EidState for EidMlsTranscriptState {
             .map(EidMlsMember::from_existing)
             .collect()
     }
+
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider> {
+        self.group
+            .members()
+            .filter(|member| !self.has_member(member).unwrap_or(true))
+            .map(EidMlsMember::from_existing)
+            .collect()
+    }
+
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider> {
+        self.get_pending_members()
+            .into_iter()
+            .filter(|member| self.is_expired(member, now))
+            .collect()
+    }
 }
 
 impl EidMlsTranscriptState {
+    fn protocol_message(message: MlsMessageIn) -> Result<ProtocolMessage, EidError> {
+        let body = message.extract();
+        if let MlsMessageInBody::PublicMessage(public_message) = body {
+            Ok(ProtocolMessage::PublicMessage(public_message))
+        } else {
+            Err(EidError::InvalidEvolvementError(format!(
+                "Expected PublicMessage, got {:?}",
+                body
+            )))
+        }
+    }
+
+    /// Process the proposals and the message of an evolvement and merge a staged commit.
+    /// The group's proposal store is changed even if this fails, so it is only called on a copy (see [EidState::apply]).
+    fn apply_messages(
+        &mut self,
+        evolvement: EidMlsEvolvement,
+        backend: &EidMlsBackend,
+    ) -> Result<Vec<MembershipEvent<EidMlsMember>>, EidError> {
+        if let EidMlsEvolvement::IN {
+            message,
+            proposals,
+            signed_state,
+            ..
+        } = evolvement
+        {
+            for proposal in proposals {
+                let protocol_message = Self::protocol_message(proposal)?;
+                let processed_message = self
+                    .group
+                    .process_message(&backend.mls_backend, protocol_message)
+                    .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+                if let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+                    processed_message.into_content()
+                {
+                    self.group.add_proposal(*queued_proposal);
+                } else {
+                    return Err(EidError::InvalidEvolvementError(
+                        "Expected a proposal.".into(),
+                    ));
+                }
+            }
+            let protocol_message = Self::protocol_message(message)?;
+            let processed_message = self
+                .group
+                .process_message(&backend.mls_backend, protocol_message)
+                .map_err(|e| EidError::InvalidEvolvementError(e.to_string()))?;
+            let members: Vec<_> = self.group.members().collect();
+            let sender = sender_member(&members, processed_message.sender());
+            let aad = processed_message.aad().to_vec();
+            match processed_message.into_content() {
+                ProcessedMessageContent::ApplicationMessage(_)
+                | ProcessedMessageContent::ExternalJoinProposalMessage(_) => Err(
+                    EidError::InvalidEvolvementError("Unexpected message type.".into()),
+                ),
+                ProcessedMessageContent::ProposalMessage(queued_proposal) => {
+                    self.group.add_proposal(*queued_proposal);
+                    Ok(vec![])
+                }
+                ProcessedMessageContent::StagedCommitMessage(staged_commit) => {
+                    let authenticated_data = EidMlsAuthenticatedData::from_aad(&aad)?;
+                    let change = staged_commit_change(&staged_commit, &members);
+                    let cross_signature = commit_cross_signature(
+                        self.eid_id().as_slice(),
+                        authenticated_data.as_ref(),
+                        sender.as_ref(),
+                        self.signature_scheme(),
+                        backend,
+                    )?;
+                    self.verify_commit(
+                        sender.as_ref(),
+                        &change,
+                        authenticated_data.as_ref(),
+                        cross_signature.as_ref(),
+                        signed_state.as_ref(),
+                    )?;
+                    let cross_signed_members = self.cross_signed_members();
+                    self.group.merge_commit(*staged_commit);
+                    self.update_after_commit(&change, authenticated_data, cross_signature);
+                    let members_after: Vec<_> = self.group.members().collect();
+                    Ok(membership_events(
+                        change,
+                        &members,
+                        &cross_signed_members,
+                        &members_after,
+                        &self.cross_signed_members(),
+                    ))
+                }
+            }
+        } else {
+            Err(EidError::InvalidEvolvementError(String::from(
+                "Expected EidMlsEvolvement::IN, got ::OUT",
+            )))
+        }
+    }
+
+    /// Verify a commit before it is merged.
+    /// The invitation of the sender must not have expired, and the signed state the commit was received with, if any,
+    /// must hold the cross signatures and invitations the state has after merging the commit.
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidEvolvementError] if the commit is invalid.
+    fn verify_commit(
+        &self,
+        sender: Option<&EidMlsMember>,
+        change: &EidChange<EidMlsMember>,
+        authenticated_data: Option<&EidMlsAuthenticatedData>,
+        cross_signature: Option<&EidMlsCrossSignature>,
+        signed_state: Option<&EidMlsSignedState>,
+    ) -> Result<(), EidError> {
+        if let Some(sender) = sender {
+            if self.is_expired(sender, None) {
+                return Err(EidError::InvalidEvolvementError(
+                    "The invitation of the sender has expired".into(),
+                ));
+            }
+        }
+        if let Some(signed_state) = signed_state {
+            let expiry = authenticated_data.and_then(EidMlsAuthenticatedData::invitation_expiry);
+            signed_state.verify_matches(
+                self.epoch() + 1,
+                &cross_signatures_after_commit(&self.cross_signatures, change, cross_signature),
+                &invitations_after_commit(
+                    &self.invitations,
+                    self.get_pending_members(),
+                    change,
+                    expiry,
+                ),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Get the signature keys of all members that have cross-signed their membership.
+    fn cross_signed_members(&self) -> Vec<Vec<u8>> {
+        cross_signed_members(&self.cross_signatures)
+    }
+
+    /// Get the signature scheme of the group's ciphersuite, which members sign with.
+    fn signature_scheme(&self) -> SignatureScheme {
+        self.group
+            .group_context()
+            .ciphersuite()
+            .signature_algorithm()
+    }
+
+    /// Remember the cross signature and the invitations of a commit that was just merged
+    /// (see [update_cross_signatures] and [update_invitations]).
+    fn update_after_commit(
+        &mut self,
+        change: &EidChange<EidMlsMember>,
+        authenticated_data: Option<EidMlsAuthenticatedData>,
+        cross_signature: Option<EidMlsCrossSignature>,
+    ) {
+        let members: Vec<_> = self.group.members().collect();
+        update_cross_signatures(&mut self.cross_signatures, cross_signature, &members);
+        let expiry = authenticated_data
+            .as_ref()
+            .and_then(EidMlsAuthenticatedData::invitation_expiry);
+        let pending_members = self.get_pending_members();
+        update_invitations(
+            &mut self.invitations,
+            &change.added,
+            expiry,
+            &pending_members,
+        );
+    }
+
+    /// True if the invitation of the member has expired in the current epoch.
+    fn is_expired(&self, member: &EidMlsMember, now: Option<u64>) -> bool {
+        is_invitation_expired(
+            &self.invitations,
+            member.credential.signature_key.as_slice(),
+            self.epoch(),
+            now,
+        )
+    }
+
     fn has_member(&self, member: &MlsMember) -> Result<bool, EidError> {
-        let leaf_nodes = self.get_leaf_nodes();
-        let leaf_node: &LeafNode = leaf_nodes
-            .iter()
-            .find(|&node| node.signature_key().as_slice().to_vec() == member.signature_key)
+        self.group
+            .members()
+            .find(|node| node.signature_key == member.signature_key)
             .ok_or_else(|| {
                 EidError::InvalidMemberError("Member index doesn't have a matching node".into())
             })?;
-        Ok(leaf_node.parent_hash().is_some())
-    }
-
-    fn get_leaf_nodes(&self) -> Vec<LeafNode> {
-        self.group
-            .export_nodes()
+        Ok(self
+            .cross_signatures
             .iter()
-            .filter_map(|node| match node {
-                Some(Node::LeafNode(leaf_node)) => Some(LeafNode::from(leaf_node.clone())),
-                Some(Node::ParentNode(_)) | None => None,
-            })
-            .collect()
+            .any(|cross_signature| cross_signature.signature_key == member.signature_key))
     }
 }
 
 impl EidMlsTranscriptState {
     pub(crate) fn new(group: PublicGroup) -> Self {
-        EidMlsTranscriptState { group }
+        EidMlsTranscriptState {
+            group,
+            cross_signatures: vec![],
+            invitations: vec![],
+        }
     }
 
     pub(crate) fn clone_serde(&self) -> Result<Self, EidError> {
         let serialized =
             serde_json::to_string(self).map_err(|e| EidError::SerializationError(e.to_string()))?;
-        let deserialized = serde_json::from_str(&serialized)
-            .map_err(|e| EidError::DeserializationError(e.to_string()))?;
-        Ok(deserialized)
+        serde_json::from_str(&serialized).map_err(|e| EidError::DeserializationError(e.to_string()))
     }
 }
 
@@ -121,10 +492,12 @@ pub enum EidMlsExportedTranscriptState {
     IN {
         group_info: MlsMessageIn,
         nodes: Vec<Option<Node>>,
+        signed_state: EidMlsSignedState,
     },
     OUT {
         group_info: MlsMessageOut,
         nodes: Vec<Option<Node>>,
+        signed_state: EidMlsSignedState,
     },
 }
 
@@ -136,16 +509,27 @@ impl Size for EidMlsExportedTranscriptState {
                 nodes.iter().map(|node| node.tls_serialized_len()).sum()
             }
         };
+        let state_len = match self {
+            EidMlsExportedTranscriptState::IN { signed_state, .. }
+            | EidMlsExportedTranscriptState::OUT { signed_state, .. } => {
+                signed_state.tls_serialized_len()
+            }
+        };
         match self {
-            Self::OUT { group_info, .. } => nodes_len + group_info.tls_serialized_len(),
-            Self::IN { group_info, .. } => nodes_len + group_info.tls_serialized_len(),
+            Self::OUT { group_info, .. } => nodes_len + state_len + group_info.tls_serialized_len(),
+            Self::IN { group_info, .. } => nodes_len + state_len + group_info.tls_serialized_len(),
         }
     }
 }
 
 impl Serialize for EidMlsExportedTranscriptState {
     fn tls_serialize<W: Write>(&self, writer: &mut W) -> Result<usize, TlsError> {
-        if let Self::OUT { group_info, nodes } = self {
+        if let Self::OUT {
+            group_info,
+            nodes,
+            signed_state,
+        } = self
+        {
             let mut bytes_written = 0;
             let msg_ser = group_info.tls_serialize_detached()?;
             bytes_written += writer.write(msg_ser.as_slice())?;
@@ -153,6 +537,9 @@ impl Serialize for EidMlsExportedTranscriptState {
             let welcome_ser = nodes.tls_serialize_detached()?;
             bytes_written += writer.write(welcome_ser.as_slice())?;
 
+            let signed_state_ser = signed_state.tls_serialize_detached()?;
+            bytes_written += writer.write(signed_state_ser.as_slice())?;
+
             Ok(bytes_written)
         } else {
             Err(TlsError::EncodingError(String::from(
@@ -169,7 +556,12 @@ impl Deserialize for EidMlsExportedTranscriptState {
     {
         let group_info = MlsMessageIn::tls_deserialize(bytes)?;
         let nodes = Vec::<Option<Node>>::tls_deserialize(bytes)?;
-        Ok(Self::IN { group_info, nodes })
+        let signed_state = EidMlsSignedState::tls_deserialize(bytes)?;
+        Ok(Self::IN {
+            group_info,
+            nodes,
+            signed_state,
+        })
     }
 }
 
@@ -184,6 +576,7 @@ impl EidExportedTranscriptState for EidMlsExportedTranscriptState {
         if let EidMlsExportedTranscriptState::IN {
             group_info: message_in,
             nodes,
+            signed_state,
         } = self
         {
             if let MlsMessageInBody::GroupInfo(verifiable_group_info) = message_in.extract() {
@@ -195,7 +588,21 @@ impl EidExportedTranscriptState for EidMlsExportedTranscriptState {
                 )
                 .map_err(|e| EidError::CreateTranscriptStateError(e.to_string()))?;
 
-                Ok(EidMlsTranscriptState::new(group))
+                let mut state = EidMlsTranscriptState::new(group);
+                // Cross signatures and invitations aren't covered by the group info, so a member has to have signed them
+                let members: Vec<_> = state.group.members().collect();
+                signed_state
+                    .verify(
+                        state.eid_id().as_slice(),
+                        state.epoch(),
+                        &members,
+                        state.signature_scheme(),
+                        backend,
+                    )
+                    .map_err(|e| EidError::ImportTranscriptStateError(e.to_string()))?;
+                state.cross_signatures = signed_state.cross_signatures;
+                state.invitations = signed_state.invitations;
+                Ok(state)
             } else {
                 Err(EidError::ImportTranscriptStateError(
                     "Expected MlsMessageInBody::GroupInfo, got another variant of MlsMessageInBody"
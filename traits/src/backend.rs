@@ -1,9 +1,66 @@
 #[cfg(feature = "test")]
 use crate::client::EidClient;
+use crate::types::EidError;
 
 /// # EidBackend
 /// Represents the interface to a provider of cryptographic functions.
 pub trait EidBackend: Default {
     #[cfg(feature = "test")]
     type ClientProvider: EidClient<BackendProvider = Self>;
+
+    /// Create a backend with the same configuration, but without any key material,
+    /// e.g. to simulate restoring a client on a new device.
+    ///
+    /// returns: [Self]
+    #[cfg(feature = "test")]
+    fn fresh(&self) -> Self;
+
+    /// Type of key the backend signs with, e.g. the operator key a transcript operator signs
+    /// [Checkpoint](crate::checkpoint::Checkpoint)s with.
+    type SigningKeyProvider;
+
+    /// Hash `data` with the backend's hash function,
+    /// e.g. to build the hash chain and the Merkle tree over the log of a [Transcript](crate::transcript::EidTranscript).
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: The data to hash
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    fn hash(&self, data: &[u8]) -> Result<Vec<u8>, EidError>;
+
+    /// Generate a new signing key, e.g. the key of a transcript operator.
+    ///
+    /// returns: [Result]<[Self::SigningKeyProvider], [EidError]>
+    fn generate_signing_key(&self) -> Result<Self::SigningKeyProvider, EidError>;
+
+    /// Get the public key of a signing key, which verifiers use to check its signatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: The signing key
+    ///
+    /// returns: [Vec]<[u8]>
+    fn public_key(&self, key: &Self::SigningKeyProvider) -> Vec<u8>;
+
+    /// Sign `data` with a signing key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: The signing key
+    /// * `data`: The data to sign
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    fn sign(&self, key: &Self::SigningKeyProvider, data: &[u8]) -> Result<Vec<u8>, EidError>;
+
+    /// Verify a signature over `data`.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key`: The public key of the signer (see [public_key](Self::public_key))
+    /// * `data`: The signed data
+    /// * `signature`: The signature
+    ///
+    /// returns: [bool] True if the signature is valid.
+    fn verify_signature(&self, public_key: &[u8], data: &[u8], signature: &[u8]) -> bool;
 }
@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+
+use crate::client::EidClient;
+use crate::evolvement::Evolvement;
+use crate::transcript::EidTranscript;
+use crate::types::EidError;
+
+/// The default number of epochs an [EvolvementBuffer] buffers evolvements ahead of the current epoch.
+/// The target epoch of an evolvement isn't verified before it is applied, so it can't be trusted to be near.
+pub const MAX_BUFFERED_EPOCHS: u64 = 64;
+
+/// # Evolvement Status
+/// What happened to an [Evolvement] that was passed to a [BufferedClient] or [BufferedTranscript].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvolvementStatus {
+    /// The evolvement was applied, followed by the given number of buffered evolvements that became applicable.
+    /// Buffered evolvements that became applicable but failed to apply are discarded and reported in `failed`.
+    Applied {
+        buffered: usize,
+        failed: Vec<FailedEvolvement>,
+    },
+    /// The evolvement applies to a future epoch and is kept until the missing epochs are filled.
+    Buffered,
+    /// The evolvement applies to a past epoch or is already buffered and was dropped.
+    Dropped,
+}
+
+/// # Failed Evolvement
+/// A buffered [Evolvement] that became applicable, but failed to apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedEvolvement {
+    /// The target epoch of the evolvement.
+    pub epoch: u64,
+    /// The description of the [EidError] applying it failed with.
+    pub error: String,
+}
+
+/// # Evolvement Buffer
+/// Holds [Evolvement]s that apply to future epochs, ordered by their [target epoch](Evolvement::target_epoch).
+/// Evolvements with the same target epoch, e.g. proposals and the commit that follows them, are kept in the order they arrived.
+/// Evolvements more than `max_distance` epochs ahead of the current epoch are rejected.
+#[derive(Debug, Clone)]
+pub struct EvolvementBuffer<E: Evolvement> {
+    evolvements: BTreeMap<u64, Vec<E>>,
+    max_distance: u64,
+}
+
+impl<E: Evolvement> Default for EvolvementBuffer<E> {
+    fn default() -> Self {
+        Self::new(MAX_BUFFERED_EPOCHS)
+    }
+}
+
+impl<E: Evolvement> EvolvementBuffer<E> {
+    /// Create an empty buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_distance`: The number of epochs evolvements are buffered ahead of the current epoch
+    ///
+    /// returns: [EvolvementBuffer]
+    pub fn new(max_distance: u64) -> Self {
+        Self {
+            evolvements: BTreeMap::new(),
+            max_distance,
+        }
+    }
+
+    /// Get the epochs between `epoch` and the highest buffered epoch that no evolvement is buffered for.
+    /// There are at most `max_distance` of them, since no evolvement further ahead is buffered.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch`: The current epoch of the client or transcript
+    ///
+    /// returns: [Vec]<[u64]>
+    pub fn missing_epochs(&self, epoch: u64) -> Vec<u64> {
+        match self.evolvements.keys().next_back() {
+            Some(&last_epoch) => (epoch..last_epoch)
+                .filter(|epoch| !self.evolvements.contains_key(epoch))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Get the number of buffered evolvements.
+    pub fn len(&self) -> usize {
+        self.evolvements.values().map(Vec::len).sum()
+    }
+
+    /// True if no evolvement is buffered.
+    pub fn is_empty(&self) -> bool {
+        self.evolvements.is_empty()
+    }
+
+    /// Apply `evolvement` if it applies to the current epoch of `inner` and apply all buffered evolvements
+    /// that become applicable afterwards. Evolvements for future epochs are buffered, stale ones are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner`: The wrapped client or transcript
+    /// * `evolvement`: The [Evolvement]
+    /// * `epoch`: Get the current epoch of `inner`
+    /// * `evolve`: Apply an evolvement to `inner`
+    ///
+    /// returns: [Result]<[EvolvementStatus], [EidError]> [EidError] if applying `evolvement` fails,
+    /// [EidError::FutureEvolvementError] if it is more than `max_distance` epochs ahead.
+    /// Buffered evolvements that fail to apply are discarded and reported in [EvolvementStatus::Applied].
+    fn evolve<I>(
+        &mut self,
+        inner: &mut I,
+        evolvement: E,
+        epoch: impl Fn(&I) -> u64,
+        evolve: impl Fn(&mut I, E) -> Result<(), EidError>,
+    ) -> Result<EvolvementStatus, EidError> {
+        let target_epoch = evolvement.target_epoch();
+        let current_epoch = epoch(inner);
+        if target_epoch < current_epoch {
+            return Ok(EvolvementStatus::Dropped);
+        }
+        if target_epoch - current_epoch > self.max_distance {
+            return Err(EidError::FutureEvolvementError(format!(
+                "The evolvement targets epoch {target_epoch}, more than {} epochs after the current epoch {current_epoch}",
+                self.max_distance
+            )));
+        }
+        if target_epoch > current_epoch {
+            return if self.insert(target_epoch, evolvement) {
+                Ok(EvolvementStatus::Buffered)
+            } else {
+                Ok(EvolvementStatus::Dropped)
+            };
+        }
+
+        evolve(inner, evolvement)?;
+        let mut buffered = 0;
+        let mut failed = vec![];
+        while let Some(evolvements) = self.evolvements.remove(&epoch(inner)) {
+            for evolvement in evolvements {
+                // A commit moves to the next epoch, evolvements of the same epoch that arrived later are stale.
+                let target_epoch = evolvement.target_epoch();
+                if target_epoch == epoch(inner) {
+                    // The incoming evolvement is already applied, so a failing buffered one doesn't fail the call
+                    match evolve(inner, evolvement) {
+                        Ok(()) => buffered += 1,
+                        Err(error) => failed.push(FailedEvolvement {
+                            epoch: target_epoch,
+                            error: error.to_string(),
+                        }),
+                    }
+                }
+            }
+        }
+        // Evolvements that became stale can never be applied
+        let current_epoch = epoch(inner);
+        self.evolvements.retain(|&epoch, _| epoch > current_epoch);
+        Ok(EvolvementStatus::Applied { buffered, failed })
+    }
+
+    /// Buffer `evolvement` unless it is already buffered.
+    ///
+    /// returns: [bool] True if the evolvement was buffered.
+    fn insert(&mut self, target_epoch: u64, evolvement: E) -> bool {
+        let evolvements = self.evolvements.entry(target_epoch).or_default();
+        if evolvements.contains(&evolvement) {
+            return false;
+        }
+        evolvements.push(evolvement);
+        true
+    }
+}
+
+/// # Buffered Client
+/// Wraps an [EidClient] and reorders the [Evolvement]s it receives.
+/// Evolvements for future epochs are held back until all missing epochs were applied,
+/// evolvements for past epochs and duplicates are dropped.
+/// The [MembershipEvent](crate::types::MembershipEvent)s of applied evolvements aren't reported,
+/// compare the client's members instead.
+pub struct BufferedClient<C: EidClient> {
+    client: C,
+    buffer: EvolvementBuffer<C::EvolvementProvider>,
+}
+
+impl<C: EidClient> BufferedClient<C> {
+    /// Wrap `client` with an empty buffer that buffers up to [MAX_BUFFERED_EPOCHS] epochs ahead.
+    pub fn new(client: C) -> Self {
+        Self::with_max_distance(client, MAX_BUFFERED_EPOCHS)
+    }
+
+    /// Wrap `client` with an empty buffer that buffers up to `max_distance` epochs ahead.
+    pub fn with_max_distance(client: C, max_distance: u64) -> Self {
+        Self {
+            client,
+            buffer: EvolvementBuffer::new(max_distance),
+        }
+    }
+
+    /// Get the wrapped [EidClient].
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Get the wrapped [EidClient] to create evolvements.
+    /// Evolvements applied to it directly bypass the buffer.
+    pub fn client_mut(&mut self) -> &mut C {
+        &mut self.client
+    }
+
+    /// Unwrap the [EidClient], dropping all buffered evolvements.
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    /// Apply the [Evolvement](EidClient::EvolvementProvider) if it applies to the client's current epoch,
+    /// buffer it if it applies to a future epoch, or drop it if it is stale or already buffered.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](EidClient::EvolvementProvider)
+    /// * `backend`: The [Backend](EidClient::BackendProvider)
+    ///
+    /// returns: [Result]<[EvolvementStatus], [EidError]> [EidError] if applying the evolvement fails
+    /// or it is too far ahead (see [EvolvementBuffer]).
+    pub fn evolve(
+        &mut self,
+        evolvement: C::EvolvementProvider,
+        backend: &C::BackendProvider,
+    ) -> Result<EvolvementStatus, EidError> {
+        self.buffer.evolve(
+            &mut self.client,
+            evolvement,
+            |client| client.epoch(),
+            |client, evolvement| client.evolve(evolvement, backend).map(|_| ()),
+        )
+    }
+
+    /// Call [evolve](Self::evolve) for each [Evolvement](EidClient::EvolvementProvider), in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvements`: The [Vec]<[EidClient::EvolvementProvider]>
+    /// * `backend`: The [Backend](EidClient::BackendProvider)
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError] if applying an evolvement fails.
+    pub fn batch_evolve(
+        &mut self,
+        evolvements: Vec<C::EvolvementProvider>,
+        backend: &C::BackendProvider,
+    ) -> Result<(), EidError> {
+        for evolvement in evolvements {
+            self.evolve(evolvement, backend)?;
+        }
+        Ok(())
+    }
+
+    /// Get the epochs the client needs evolvements for before it can apply the buffered ones.
+    ///
+    /// returns: [Vec]<[u64]>
+    pub fn missing_epochs(&self) -> Vec<u64> {
+        self.buffer.missing_epochs(self.client.epoch())
+    }
+
+    /// Get the [EvolvementBuffer] of the client.
+    pub fn buffer(&self) -> &EvolvementBuffer<C::EvolvementProvider> {
+        &self.buffer
+    }
+}
+
+/// # Buffered Transcript
+/// Wraps an [EidTranscript] and reorders the [Evolvement]s it receives, like a [BufferedClient].
+pub struct BufferedTranscript<T: EidTranscript> {
+    transcript: T,
+    buffer: EvolvementBuffer<T::EvolvementProvider>,
+}
+
+impl<T: EidTranscript> BufferedTranscript<T> {
+    /// Wrap `transcript` with an empty buffer that buffers up to [MAX_BUFFERED_EPOCHS] epochs ahead.
+    pub fn new(transcript: T) -> Self {
+        Self::with_max_distance(transcript, MAX_BUFFERED_EPOCHS)
+    }
+
+    /// Wrap `transcript` with an empty buffer that buffers up to `max_distance` epochs ahead.
+    pub fn with_max_distance(transcript: T, max_distance: u64) -> Self {
+        Self {
+            transcript,
+            buffer: EvolvementBuffer::new(max_distance),
+        }
+    }
+
+    /// Get the wrapped [EidTranscript].
+    pub fn transcript(&self) -> &T {
+        &self.transcript
+    }
+
+    /// Unwrap the [EidTranscript], dropping all buffered evolvements.
+    pub fn into_inner(self) -> T {
+        self.transcript
+    }
+
+    /// Apply the [Evolvement](EidTranscript::EvolvementProvider) if it applies to the transcript's current epoch,
+    /// buffer it if it applies to a future epoch, or drop it if it is stale or already buffered.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](EidTranscript::EvolvementProvider)
+    /// * `backend`: The [Backend](EidTranscript::BackendProvider)
+    ///
+    /// returns: [Result]<[EvolvementStatus], [EidError]> [EidError] if applying the evolvement fails
+    /// or it is too far ahead (see [EvolvementBuffer]).
+    pub fn evolve(
+        &mut self,
+        evolvement: T::EvolvementProvider,
+        backend: &T::BackendProvider,
+    ) -> Result<EvolvementStatus, EidError> {
+        self.buffer.evolve(
+            &mut self.transcript,
+            evolvement,
+            |transcript| transcript.epoch(),
+            |transcript, evolvement| transcript.evolve(evolvement, backend).map(|_| ()),
+        )
+    }
+
+    /// Call [evolve](Self::evolve) for each [Evolvement](EidTranscript::EvolvementProvider), in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvements`: The [Vec]<[EidTranscript::EvolvementProvider]>
+    /// * `backend`: The [Backend](EidTranscript::BackendProvider)
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError] if applying an evolvement fails.
+    pub fn batch_evolve(
+        &mut self,
+        evolvements: Vec<T::EvolvementProvider>,
+        backend: &T::BackendProvider,
+    ) -> Result<(), EidError> {
+        for evolvement in evolvements {
+            self.evolve(evolvement, backend)?;
+        }
+        Ok(())
+    }
+
+    /// Get the epochs the transcript needs evolvements for before it can apply the buffered ones.
+    ///
+    /// returns: [Vec]<[u64]>
+    pub fn missing_epochs(&self) -> Vec<u64> {
+        self.buffer.missing_epochs(self.transcript.epoch())
+    }
+
+    /// Get the [EvolvementBuffer] of the transcript.
+    pub fn buffer(&self) -> &EvolvementBuffer<T::EvolvementProvider> {
+        &self.buffer
+    }
+}
@@ -0,0 +1,121 @@
+//! # Checkpoint
+//! A transcript operator signs [Checkpoint]s of its [EidTranscript](crate::transcript::EidTranscript) with an operator key.
+//! Clients and auditors gossip the [SignedCheckpoint]s they received and compare them with their own view of the transcript
+//! (see [EidTranscript::verify_checkpoint](crate::transcript::EidTranscript::verify_checkpoint)).
+//! Two valid checkpoints of the same EID that disagree about the same log prove that the operator shows a split view.
+
+use tls_codec::{Serialize, TlsDeserialize, TlsSerialize, TlsSize};
+
+use crate::backend::EidBackend;
+use crate::types::{EidError, EidId};
+
+/// # Checkpoint
+/// The view of a transcript at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct Checkpoint {
+    /// The [EidId] of the EID the transcript belongs to.
+    pub eid_id: EidId,
+    /// The epoch of the transcript's current state.
+    pub epoch: u64,
+    /// The number of entries in the log.
+    pub log_size: u64,
+    /// The head of the hash chain over the log (see [EidTranscript::head_hash](crate::transcript::EidTranscript::head_hash)).
+    pub head_hash: Vec<u8>,
+    /// The digest of the members of the transcript's current state (see [members_digest]).
+    pub members_digest: Vec<u8>,
+}
+
+/// The content an operator signs to issue a [Checkpoint].
+#[derive(TlsSerialize, TlsSize)]
+struct CheckpointContent {
+    label: Vec<u8>,
+    checkpoint: Checkpoint,
+}
+
+impl Checkpoint {
+    /// Get the content an operator signs to issue the checkpoint.
+    /// It is labeled, so that it can't be confused with signatures for other purposes.
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    pub fn signed_content(&self) -> Result<Vec<u8>, EidError> {
+        CheckpointContent {
+            label: b"EID checkpoint".to_vec(),
+            checkpoint: self.clone(),
+        }
+        .tls_serialize_detached()
+        .map_err(|e| EidError::SerializationError(e.to_string()))
+    }
+
+    /// Sign the checkpoint with the key of the transcript operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_key`: The signing key of the operator (see [EidBackend::generate_signing_key])
+    /// * `backend`: The [EidBackend]
+    ///
+    /// returns: [Result]<[SignedCheckpoint], [EidError]>
+    pub fn sign<B: EidBackend>(
+        self,
+        operator_key: &B::SigningKeyProvider,
+        backend: &B,
+    ) -> Result<SignedCheckpoint, EidError> {
+        let signature = backend.sign(operator_key, &self.signed_content()?)?;
+        Ok(SignedCheckpoint {
+            checkpoint: self,
+            signature,
+        })
+    }
+}
+
+/// # Signed Checkpoint
+/// A [Checkpoint] with the signature of the transcript operator.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct SignedCheckpoint {
+    pub checkpoint: Checkpoint,
+    pub signature: Vec<u8>,
+}
+
+impl SignedCheckpoint {
+    /// Verify that the checkpoint was signed by the transcript operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_public_key`: The public key of the operator (see [EidBackend::public_key])
+    /// * `backend`: The [EidBackend]
+    ///
+    /// returns: [Result]<&[Checkpoint], [EidError]> [EidError::InvalidCheckpointError] if the signature is invalid.
+    pub fn verify<B: EidBackend>(
+        &self,
+        operator_public_key: &[u8],
+        backend: &B,
+    ) -> Result<&Checkpoint, EidError> {
+        if backend.verify_signature(
+            operator_public_key,
+            &self.checkpoint.signed_content()?,
+            &self.signature,
+        ) {
+            Ok(&self.checkpoint)
+        } else {
+            Err(EidError::InvalidCheckpointError("Invalid signature".into()))
+        }
+    }
+}
+
+/// Get the digest of the members of an EID, independent of their order.
+///
+/// # Arguments
+///
+/// * `backend`: The [EidBackend] whose hash function is used
+/// * `members`: A serialization of each member that identifies it, e.g. its signature key
+///
+/// returns: [Result]<[Vec]<[u8]>, [EidError]>
+pub fn members_digest<B: EidBackend>(
+    backend: &B,
+    mut members: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, EidError> {
+    members.sort();
+    let serialized = members
+        .tls_serialize_detached()
+        .map_err(|e| EidError::SerializationError(e.to_string()))?;
+    backend.hash(&serialized)
+}
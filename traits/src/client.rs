@@ -1,14 +1,19 @@
 #[cfg(feature = "test")]
 use std::fmt::Debug;
 
+use tls_codec::{Deserialize, Serialize};
+
 use crate::backend::EidBackend;
-use crate::evolvement::Evolvement;
+use crate::evolvement::{Evolvement, EvolvementSummary, EvolvementVerdict};
 use crate::member::Member;
+use crate::proof::EidMembershipProof;
 use crate::state::EidState;
 use crate::transcript::EidExportedTranscriptState;
 #[cfg(feature = "test")]
 use crate::transcript::EidTranscript;
-use crate::types::EidError;
+use crate::types::{
+    ChallengeResponse, EidChange, EidError, EidId, InvitationExpiry, MembershipEvent,
+};
 
 /// # EidClient
 /// A Client of an EID that can create new [Evolvement]s and evolve its [EidState] by applying any [Evolvement]s.
@@ -33,6 +38,16 @@ pub trait EidClient {
         TranscriptStateProvider = Self::TranscriptStateProvider,
         BackendProvider = Self::BackendProvider,
     >;
+    /// Type of [Membership Proof](Self::MembershipProofProvider) the client creates to prove its membership to third parties.
+    type MembershipProofProvider: EidMembershipProof<
+        MemberProvider = Self::MemberProvider,
+        StateProvider = Self::TranscriptStateProvider,
+        BackendProvider = Self::BackendProvider,
+    >;
+    /// Type of [State](Self::ExportedClientStateProvider) that can be exported from a client to persist it
+    /// and is used to restore the client later on.
+    type ExportedClientStateProvider: Serialize + Deserialize;
+
     /// Type of [EidBackend](Self::BackendProvider) this [EidClient](Self) uses.
     type BackendProvider: EidBackend;
     type KeyProvider;
@@ -51,6 +66,7 @@ pub trait EidClient {
     ///
     /// # Arguments
     ///
+    /// * `eid_id`: The [EidId] of the new EID. A random [EidId] is chosen if it is [None].
     /// * `initial_member`: The first [Member](Self::MemberProvider)
     /// * `key_pair`: The [Member](Self::MemberProvider)'s key material
     /// * `backend`: The [Member](Self::BackendProvider).
@@ -58,6 +74,7 @@ pub trait EidClient {
     /// returns: [Result]<[Self], [EidError]> [Self] if creation of the EID succeeds, [EidError] otherwise
     ///
     fn create_eid(
+        eid_id: Option<EidId>,
         initial_member: &Self::MemberProvider,
         key_pair: Self::KeyProvider,
         backend: &Self::BackendProvider,
@@ -114,6 +131,87 @@ pub trait EidClient {
     where
         Self: Sized;
 
+    /// Create an [Evolvement](Self::EvolvementProvider) to revoke the invitation of a member that hasn't cross-signed its membership yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `member`: The pending [Member](Self::MemberProvider) (see [get_pending_members](Self::get_pending_members))
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]> [EidError::InvalidMemberError] if the member has no pending invitation.
+    ///
+    fn revoke_invitation(
+        &mut self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create a single [Evolvement](Self::EvolvementProvider) that adds several [Member](Self::MemberProvider)s to the EID at once.
+    /// Each added member uses the same evolvement to create its client from invitation (see [create_from_invitation](Self::create_from_invitation)).
+    ///
+    /// # Arguments
+    ///
+    /// * `members`: The [Member](Self::MemberProvider)s
+    /// * `backend`: The [EidBackend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Evolvement](Self::EvolvementProvider), [EidError]> [EidError::AddMemberError] if `members` is empty.
+    fn add_many(
+        &mut self,
+        members: &[Self::MemberProvider],
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) to add a [Member](Self::MemberProvider) whose invitation expires
+    /// if the member doesn't cross-sign its membership in time, like [add](Self::add) otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `member`: The [Member](Self::MemberProvider)
+    /// * `expiry`: The [InvitationExpiry], e.g. `InvitationExpiry::Epoch(client.epoch() + 10)`
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Evolvement](Self::EvolvementProvider), [EidError]>
+    fn add_with_expiry(
+        &mut self,
+        member: &Self::MemberProvider,
+        expiry: InvitationExpiry,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create a single [Evolvement](Self::EvolvementProvider) that removes several [Member](Self::MemberProvider)s from the EID at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `members`: The [Member](Self::MemberProvider)s
+    /// * `backend`: The [EidBackend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Evolvement](Self::EvolvementProvider), [EidError]> [EidError::RemoveMemberError] if `members` is empty.
+    fn remove_many(
+        &mut self,
+        members: &[Self::MemberProvider],
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create a single [Evolvement](Self::EvolvementProvider) that atomically removes `old_member` and adds `new_member`,
+    /// e.g. to replace a lost device.
+    /// The new member uses the evolvement to create its client from invitation (see [create_from_invitation](Self::create_from_invitation)).
+    /// The evolvement only contains the replacement, so it fails with [EidError::CommitError] if there are pending proposals
+    /// (see [commit_pending_proposals](Self::commit_pending_proposals)).
+    ///
+    /// # Arguments
+    ///
+    /// * `old_member`: The [Member](Self::MemberProvider) to remove
+    /// * `new_member`: The [Member](Self::MemberProvider) to add
+    /// * `backend`: The [EidBackend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Evolvement](Self::EvolvementProvider), [EidError]>
+    fn replace(
+        &mut self,
+        old_member: &Self::MemberProvider,
+        new_member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
     /// Create an [Evolvement](Self::EvolvementProvider) to update your own key material.
     ///
     /// # Arguments
@@ -127,6 +225,87 @@ pub trait EidClient {
         backend: &Self::BackendProvider,
     ) -> Result<Self::EvolvementProvider, EidError>;
 
+    /// Create an [Evolvement](Self::EvolvementProvider) that proposes to remove yourself from the EID.
+    /// The proposal takes effect once any remaining member commits it (see [commit_pending_proposals](Self::commit_pending_proposals)).
+    /// After applying that commit, the client is inactive and rejects any further [Evolvement](Self::EvolvementProvider).
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]>
+    ///
+    fn leave(
+        &mut self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) that proposes to add a [Member](Self::MemberProvider) to the EID.
+    /// The proposal takes effect once any member commits it (see [commit_pending_proposals](Self::commit_pending_proposals)).
+    /// The added member uses that commit to create its client from invitation (see [create_from_invitation](Self::create_from_invitation)).
+    ///
+    /// # Arguments
+    ///
+    /// * `member`: The [Member](Self::MemberProvider)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]>
+    ///
+    fn propose_add(
+        &mut self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) that proposes to remove a [Member](Self::MemberProvider) from the EID.
+    /// The proposal takes effect once any member commits it (see [commit_pending_proposals](Self::commit_pending_proposals)).
+    ///
+    /// # Arguments
+    ///
+    /// * `member`: The [Member](Self::MemberProvider)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]>
+    ///
+    fn propose_remove(
+        &mut self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) that proposes to update your own key material.
+    /// The proposal takes effect once any other member commits it (see [commit_pending_proposals](Self::commit_pending_proposals)).
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]>
+    ///
+    fn propose_update(
+        &mut self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) that commits all pending proposals the client has received.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]> [EidError] if there are no pending proposals.
+    ///
+    fn commit_pending_proposals(
+        &mut self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
+    /// True if the client is still a member of the EID.
+    /// A client becomes inactive once it was removed from the EID, e.g. after [leaving](Self::leave) it.
+    ///
+    /// returns: [bool]
+    fn is_active(&self) -> bool;
+
     /// Apply the next [Evolvement](Self::EvolvementProvider), changing the client's state.
     ///
     /// # Arguments
@@ -134,34 +313,64 @@ pub trait EidClient {
     /// * `evolvement`: The [Evolvement](Self::EvolvementProvider)
     /// * `backend`: The [Backend](Self::BackendProvider)
     ///
-    /// returns: [Result]<[()], [EidError]> [EidError] If the [Self::EvolvementProvider] is invalid.
+    /// returns: [Result]<[Vec]<[MembershipEvent]<[Self::MemberProvider]>>, [EidError]> The changes to the members of the EID.
+    /// [EidError] If the [Self::EvolvementProvider] is invalid or the client is inactive.
+    /// [EidError::StaleEvolvementError] or [EidError::FutureEvolvementError] if it doesn't apply to the client's current epoch.
+    /// [EidError::WrongEidError] if it belongs to a different EID.
+    /// [EidError::ConflictingEvolvementError] if it competes with the client's own pending evolvement (see [resolve_conflict](Self::resolve_conflict)).
     ///
     fn evolve(
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError>;
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError>;
+
+    /// Check whether the client could apply an [Evolvement](Self::EvolvementProvider), without changing its state.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](Self::EvolvementProvider)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [EvolvementVerdict]
+    ///
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict;
+
+    /// Describe the change an [Evolvement](Self::EvolvementProvider) makes, without changing the client's state.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](Self::EvolvementProvider), which applies to the client's epoch
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[EvolvementSummary]<[Self::MemberProvider]>, [EidError]> [EidError] if the evolvement is invalid.
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError>;
 
     /// Evolve the client's state by calling [evolve](Self::evolve)
     /// for each [Evolvement](Self::EvolvementProvider) in a [Vec] of [Evolvement](Self::EvolvementProvider)s.
+    /// The batch is atomic: if any evolvement fails, the client's state is rolled back to the state before the batch.
     ///
     /// # Arguments
     ///
     /// * `evolvements`: The [Vec]<[Self::EvolvementProvider]>
     /// * `backend`: The [Self::BackendProvider]
     ///
-    /// returns: [Result]<[()], [EidError]>) [EidError] If any [Self::EvolvementProvider] is invalid.
+    /// returns: [Result]<[Vec]<[MembershipEvent]<[Self::MemberProvider]>>, [EidError]>) The changes to the members of the EID, in the order of the evolvements.
+    /// [EidError] If any [Self::EvolvementProvider] is invalid.
     ///
     fn batch_evolve(
         &mut self,
         evolvements: Vec<Self::EvolvementProvider>,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError> {
-        for evolvement in evolvements.iter() {
-            self.evolve(evolvement.clone(), backend)?;
-        }
-        Ok(())
-    }
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError>;
 
     /// Cross-sign a clients membership.
     /// A member that was invited to a group via [add](Self::add) needs to cross-sign to be part of the EID.
@@ -177,11 +386,98 @@ pub trait EidClient {
         backend: &Self::BackendProvider,
     ) -> Result<Self::EvolvementProvider, EidError>;
 
+    /// Get the change the client's pending [Evolvement](Self::EvolvementProvider) makes to the EID.
+    /// An evolvement that moves the EID to the next epoch is pending from its creation until the client applies it.
+    ///
+    /// returns: [Option]<[EidChange]> [None] if the client has no pending evolvement.
+    fn pending_change(&self) -> Option<EidChange<Self::MemberProvider>>;
+
+    /// Resolve a conflict between the client's pending [Evolvement](Self::EvolvementProvider) and a competing one
+    /// for the same epoch, e.g. after [evolve](Self::evolve) failed with [EidError::ConflictingEvolvementError].
+    /// The pending evolvement is discarded and the competing one is applied instead.
+    /// The discarded change can be created again on top of the new epoch with [recreate](Self::recreate).
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The competing [Evolvement](Self::EvolvementProvider) that won
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Option]<[EidChange]>, [EidError]> The discarded change, [None] if the client had no pending evolvement.
+    /// [EidError] if the competing evolvement is invalid. The client is left unchanged and keeps its pending evolvement.
+    fn resolve_conflict(
+        &mut self,
+        evolvement: Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Option<EidChange<Self::MemberProvider>>, EidError>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) that makes a change again, e.g. one that was discarded by [resolve_conflict](Self::resolve_conflict).
+    /// Members that were added or removed in the meantime are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `change`: The [EidChange]
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]> [EidError::CommitError] if nothing is left to change.
+    fn recreate(
+        &mut self,
+        change: &EidChange<Self::MemberProvider>,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError>;
+
     /// Get all members of the EID.
     ///
     /// returns: [Vec]<[Self::MemberProvider]>
     fn get_members(&self) -> Vec<Self::MemberProvider>;
 
+    /// Get all members that were invited to the EID but haven't cross-signed their membership yet.
+    /// These members aren't part of [get_members](Self::get_members).
+    ///
+    /// returns: [Vec]<[Self::MemberProvider]>
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider>;
+
+    /// Get all pending members whose invitation has expired (see [EidState::get_expired_members]).
+    ///
+    /// # Arguments
+    ///
+    /// * `now`: The current time, used for invitations that expire at a time
+    ///
+    /// returns: [Vec]<[Self::MemberProvider]>
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider>;
+
+    /// Create an [Evolvement](Self::EvolvementProvider) that removes all pending members whose invitation has expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `now`: The current time, used for invitations that expire at a time
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::EvolvementProvider], [EidError]> [EidError::InvalidMemberError] if no invitation has expired.
+    ///
+    fn prune_expired_members(
+        &mut self,
+        now: Option<u64>,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::EvolvementProvider, EidError> {
+        let expired_members = self.get_expired_members(now);
+        if expired_members.is_empty() {
+            return Err(EidError::InvalidMemberError(
+                "No invitation has expired".into(),
+            ));
+        }
+        self.remove_many(&expired_members, backend)
+    }
+
+    /// Get the epoch of the client's [EidState] (see [EidState::epoch]).
+    ///
+    /// returns: [u64]
+    fn epoch(&self) -> u64;
+
+    /// Get the [EidId] of the EID the client belongs to (see [EidState::eid_id]).
+    ///
+    /// returns: [EidId]
+    fn eid_id(&self) -> EidId;
+
     /// Export the clients EidState as [Self::ExportedTranscriptStateProvider].
     /// The exported state will be the trusted trusted EID state used to create a Transcript.
     ///
@@ -196,6 +492,73 @@ pub trait EidClient {
         backend: &Self::BackendProvider,
     ) -> Result<Self::ExportedTranscriptStateProvider, EidError>;
 
+    /// Create a [Self::MembershipProofProvider] that proves the client's membership in the current epoch.
+    /// The proof can be verified by third parties that don't hold the EID's transcript, but trust a checkpoint of it
+    /// (see [EidMembershipProof::verify]).
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[Self::MembershipProofProvider], [EidError]>
+    /// [EidError::InactiveClientError] if the client isn't a member of the EID or hasn't cross-signed its membership yet.
+    ///
+    fn prove_membership(
+        &self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MembershipProofProvider, EidError>;
+
+    /// Sign a challenge of a relying party to prove that the client controls the key of a current member.
+    /// The relying party verifies the response against the members of the EID
+    /// (see [EidState::verify_challenge] and [EidTranscript::verify_challenge](crate::transcript::EidTranscript::verify_challenge)).
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce`: A fresh nonce chosen by the relying party
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[ChallengeResponse], [EidError]> [EidError::InactiveClientError] if the client isn't a member of the EID.
+    ///
+    fn sign_challenge(
+        &self,
+        nonce: &[u8],
+        backend: &Self::BackendProvider,
+    ) -> Result<ChallengeResponse, EidError>;
+
+    /// Export the client's complete state as [Self::ExportedClientStateProvider], including its private key material.
+    /// The exported state can be persisted and used to restore the client (see [restore_client_state](Self::restore_client_state)).
+    /// It only holds the key material of this client, even if other clients share the backend.
+    ///
+    /// **Note:** The exported state contains the client's secret keys. Store it encrypted and protect it like the keys themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[Self::ExportedClientStateProvider], [EidError]>
+    ///
+    fn export_client_state(
+        &self,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::ExportedClientStateProvider, EidError>;
+
+    /// Restore a client from a state that was exported with [export_client_state](Self::export_client_state).
+    /// The restored client continues at the point in the EID's history at which the state was exported.
+    ///
+    /// # Arguments
+    ///
+    /// * `state`: The [Self::ExportedClientStateProvider]
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[Self], [EidError]>
+    ///
+    fn restore_client_state(
+        state: Self::ExportedClientStateProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self, EidError>
+    where
+        Self: Sized;
+
     /// Generate a [member](Self::MemberProvider) that can be added to an EID.
     ///
     /// # Arguments
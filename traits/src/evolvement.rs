@@ -1,6 +1,114 @@
 use tls_codec::{Deserialize, Serialize};
 
+use crate::types::{EidError, EidId};
+
 /// # Evolvement
 /// Represents one change in an EID.
 /// The History of an EID is the log of all evolvements.
-pub trait Evolvement: Clone + Serialize + Deserialize {}
+pub trait Evolvement: Clone + PartialEq + Serialize + Deserialize {
+    /// Get the epoch of the [EidState](crate::state::EidState) this evolvement applies to.
+    /// Applying an evolvement that changes the member list moves the state to the next epoch.
+    ///
+    /// returns: [u64]
+    fn target_epoch(&self) -> u64;
+
+    /// Get the [EidId] of the EID this evolvement belongs to.
+    ///
+    /// returns: [EidId]
+    fn eid_id(&self) -> EidId;
+}
+
+/// # Evolvement Verdict
+/// The result of validating an [Evolvement] against a state without applying it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvolvementVerdict {
+    /// The evolvement can be applied.
+    Valid,
+    /// The evolvement applies to a past epoch.
+    Stale,
+    /// The evolvement applies to a future epoch.
+    Future,
+    /// The evolvement belongs to a different EID.
+    WrongEid,
+    /// The evolvement wasn't created by a member of the EID.
+    WrongSender,
+    /// The evolvement neither changes the EID nor proposes a change, e.g. an application message.
+    NotACommit,
+    /// The signature of the evolvement is invalid.
+    BadSignature,
+    /// The evolvement is invalid for another reason.
+    Invalid(String),
+}
+
+impl EvolvementVerdict {
+    /// True if the evolvement can be applied.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
+
+impl From<EidError> for EvolvementVerdict {
+    fn from(error: EidError) -> Self {
+        match error {
+            EidError::StaleEvolvementError(_) => Self::Stale,
+            EidError::FutureEvolvementError(_) => Self::Future,
+            EidError::WrongEidError(_) => Self::WrongEid,
+            error => Self::Invalid(error.to_string()),
+        }
+    }
+}
+
+/// # Evolvement Kind
+/// The kind of change an [Evolvement] makes to an EID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolvementKind {
+    /// Members are added to the EID.
+    Add,
+    /// Members are removed from the EID.
+    Remove,
+    /// Members are removed from and added to the EID in one evolvement.
+    Replace,
+    /// A member updates its key material.
+    Update,
+    /// A member cross-signs its membership after joining the EID.
+    CrossSign,
+    /// A member proposes a change that takes effect once it is committed.
+    Proposal,
+}
+
+/// # Evolvement Summary
+/// Describes who changed what with an [Evolvement], e.g. to show an audit trail of member changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvolvementSummary<M> {
+    /// The kind of change.
+    pub kind: EvolvementKind,
+    /// The member that created the evolvement, if it is known.
+    pub sender: Option<M>,
+    /// Members that are added, or proposed to be added.
+    pub added: Vec<M>,
+    /// Members that are removed, or proposed to be removed.
+    pub removed: Vec<M>,
+    /// The epoch of the state after applying the evolvement.
+    pub epoch: u64,
+}
+
+impl EvolvementKind {
+    /// Get the kind of a commit from the members it adds and removes.
+    ///
+    /// # Arguments
+    ///
+    /// * `added`: True if the commit adds members
+    /// * `removed`: True if the commit removes members
+    /// * `cross_signs`: True if the sender hasn't cross-signed its membership before
+    ///
+    /// returns: [EvolvementKind]
+    pub fn of_commit(added: bool, removed: bool, cross_signs: bool) -> Self {
+        match (added, removed) {
+            (true, true) => Self::Replace,
+            (true, false) => Self::Add,
+            (false, true) => Self::Remove,
+            (false, false) if cross_signs => Self::CrossSign,
+            (false, false) => Self::Update,
+        }
+    }
+}
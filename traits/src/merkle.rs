@@ -0,0 +1,306 @@
+//! # Merkle Tree
+//! An append-only Merkle tree over the log of an [EidTranscript](crate::transcript::EidTranscript),
+//! following the Merkle tree of Certificate Transparency (RFC 9162).
+//! Auditors remember the roots of the log and check with an [InclusionProof] that an [Evolvement](crate::evolvement::Evolvement)
+//! is in the log and with a [ConsistencyProof] that the log extends a log they have seen before,
+//! without holding the whole transcript.
+
+use tls_codec::{TlsDeserialize, TlsSerialize, TlsSize};
+
+use crate::backend::EidBackend;
+use crate::types::EidError;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Get the hash of a leaf of the Merkle tree.
+///
+/// # Arguments
+///
+/// * `backend`: The [EidBackend] whose hash function is used
+/// * `data`: The serialization of the log entry
+///
+/// returns: [Result]<[Vec]<[u8]>, [EidError]>
+pub fn leaf_hash<B: EidBackend>(backend: &B, data: &[u8]) -> Result<Vec<u8>, EidError> {
+    backend.hash(&[&[LEAF_PREFIX], data].concat())
+}
+
+/// Get the root of the Merkle tree over `leaves`.
+///
+/// # Arguments
+///
+/// * `backend`: The [EidBackend] whose hash function is used
+/// * `leaves`: The hashes of the leaves (see [leaf_hash])
+///
+/// returns: [Result]<[Vec]<[u8]>, [EidError]>
+pub fn root<B: EidBackend>(backend: &B, leaves: &[Vec<u8>]) -> Result<Vec<u8>, EidError> {
+    match leaves.len() {
+        0 => backend.hash(&[]),
+        1 => Ok(leaves[0].clone()),
+        size => {
+            let split = split(size);
+            node_hash(
+                backend,
+                &root(backend, &leaves[..split])?,
+                &root(backend, &leaves[split..])?,
+            )
+        }
+    }
+}
+
+fn node_hash<B: EidBackend>(backend: &B, left: &[u8], right: &[u8]) -> Result<Vec<u8>, EidError> {
+    backend.hash(&[&[NODE_PREFIX], left, right].concat())
+}
+
+/// The largest power of two smaller than `size`, which is the size of the left subtree.
+fn split(size: usize) -> usize {
+    let mut split = 1;
+    while split * 2 < size {
+        split *= 2;
+    }
+    split
+}
+
+/// Right-shift `first` and `second` until `stop` holds for `first`.
+fn shift_until(first: &mut u64, second: &mut u64, stop: impl Fn(u64) -> bool) {
+    while !stop(*first) {
+        *first >>= 1;
+        *second >>= 1;
+    }
+}
+
+/// # Inclusion Proof
+/// Proves that the leaf at `leaf_index` is part of a Merkle tree with `tree_size` leaves.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct InclusionProof {
+    pub leaf_index: u64,
+    pub tree_size: u64,
+    pub path: Vec<Vec<u8>>,
+}
+
+impl InclusionProof {
+    /// Create a proof that the leaf at `leaf_index` is part of the Merkle tree over `leaves`.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [EidBackend] whose hash function is used
+    /// * `leaves`: The hashes of the leaves (see [leaf_hash])
+    /// * `leaf_index`: The index of the leaf
+    ///
+    /// returns: [Result]<[InclusionProof], [EidError]> [EidError::InvalidMerkleProofError] if the tree has no such leaf.
+    pub fn new<B: EidBackend>(
+        backend: &B,
+        leaves: &[Vec<u8>],
+        leaf_index: usize,
+    ) -> Result<Self, EidError> {
+        if leaf_index >= leaves.len() {
+            return Err(EidError::InvalidMerkleProofError(format!(
+                "Leaf {} is not part of a tree with {} leaves",
+                leaf_index,
+                leaves.len()
+            )));
+        }
+        Ok(Self {
+            leaf_index: leaf_index as u64,
+            tree_size: leaves.len() as u64,
+            path: Self::path(backend, leaves, leaf_index)?,
+        })
+    }
+
+    fn path<B: EidBackend>(
+        backend: &B,
+        leaves: &[Vec<u8>],
+        leaf_index: usize,
+    ) -> Result<Vec<Vec<u8>>, EidError> {
+        if leaves.len() <= 1 {
+            return Ok(vec![]);
+        }
+        let split = split(leaves.len());
+        let (mut path, sibling) = if leaf_index < split {
+            (
+                Self::path(backend, &leaves[..split], leaf_index)?,
+                root(backend, &leaves[split..])?,
+            )
+        } else {
+            (
+                Self::path(backend, &leaves[split..], leaf_index - split)?,
+                root(backend, &leaves[..split])?,
+            )
+        };
+        path.push(sibling);
+        Ok(path)
+    }
+
+    /// Verify that a leaf is part of the Merkle tree with the root `root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [EidBackend] whose hash function is used
+    /// * `leaf_hash`: The hash of the leaf (see [leaf_hash])
+    /// * `root`: The root of the tree with [tree_size](Self::tree_size) leaves
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidMerkleProofError] if the proof is invalid.
+    pub fn verify<B: EidBackend>(
+        &self,
+        backend: &B,
+        leaf_hash: &[u8],
+        root: &[u8],
+    ) -> Result<(), EidError> {
+        let invalid = || EidError::InvalidMerkleProofError("Invalid inclusion proof".into());
+        if self.leaf_index >= self.tree_size {
+            return Err(invalid());
+        }
+        let mut first = self.leaf_index;
+        let mut second = self.tree_size - 1;
+        let mut hash = leaf_hash.to_vec();
+        for sibling in &self.path {
+            if second == 0 {
+                return Err(invalid());
+            }
+            if first & 1 == 1 || first == second {
+                hash = node_hash(backend, sibling, &hash)?;
+                shift_until(&mut first, &mut second, |first| {
+                    first & 1 == 1 || first == 0
+                });
+            } else {
+                hash = node_hash(backend, &hash, sibling)?;
+            }
+            first >>= 1;
+            second >>= 1;
+        }
+        if second == 0 && hash == root {
+            Ok(())
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+/// # Consistency Proof
+/// Proves that the Merkle tree with `first_size` leaves is a prefix of the Merkle tree with `second_size` leaves.
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct ConsistencyProof {
+    pub first_size: u64,
+    pub second_size: u64,
+    pub path: Vec<Vec<u8>>,
+}
+
+impl ConsistencyProof {
+    /// Create a proof that the Merkle tree over the first `first_size` leaves is a prefix of the tree over `leaves`.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [EidBackend] whose hash function is used
+    /// * `leaves`: The hashes of the leaves (see [leaf_hash])
+    /// * `first_size`: The size of the earlier tree
+    ///
+    /// returns: [Result]<[ConsistencyProof], [EidError]> [EidError::InvalidMerkleProofError] if `first_size` is 0
+    /// or larger than the number of leaves.
+    pub fn new<B: EidBackend>(
+        backend: &B,
+        leaves: &[Vec<u8>],
+        first_size: usize,
+    ) -> Result<Self, EidError> {
+        if first_size == 0 || first_size > leaves.len() {
+            return Err(EidError::InvalidMerkleProofError(format!(
+                "Can't prove consistency of a tree with {} leaves with a tree with {} leaves",
+                first_size,
+                leaves.len()
+            )));
+        }
+        Ok(Self {
+            first_size: first_size as u64,
+            second_size: leaves.len() as u64,
+            path: Self::path(backend, leaves, first_size, true)?,
+        })
+    }
+
+    fn path<B: EidBackend>(
+        backend: &B,
+        leaves: &[Vec<u8>],
+        first_size: usize,
+        complete: bool,
+    ) -> Result<Vec<Vec<u8>>, EidError> {
+        if first_size == leaves.len() {
+            // The root of a complete subtree is known to the verifier
+            return if complete {
+                Ok(vec![])
+            } else {
+                Ok(vec![root(backend, leaves)?])
+            };
+        }
+        let split = split(leaves.len());
+        let (mut path, sibling) = if first_size <= split {
+            (
+                Self::path(backend, &leaves[..split], first_size, complete)?,
+                root(backend, &leaves[split..])?,
+            )
+        } else {
+            (
+                Self::path(backend, &leaves[split..], first_size - split, false)?,
+                root(backend, &leaves[..split])?,
+            )
+        };
+        path.push(sibling);
+        Ok(path)
+    }
+
+    /// Verify that the Merkle tree with the root `first_root` is a prefix of the tree with the root `second_root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [EidBackend] whose hash function is used
+    /// * `first_root`: The root of the tree with [first_size](Self::first_size) leaves
+    /// * `second_root`: The root of the tree with [second_size](Self::second_size) leaves
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidMerkleProofError] if the proof is invalid.
+    pub fn verify<B: EidBackend>(
+        &self,
+        backend: &B,
+        first_root: &[u8],
+        second_root: &[u8],
+    ) -> Result<(), EidError> {
+        let invalid = || EidError::InvalidMerkleProofError("Invalid consistency proof".into());
+        if self.first_size == 0 || self.first_size > self.second_size {
+            return Err(invalid());
+        }
+        if self.first_size == self.second_size {
+            return if self.path.is_empty() && first_root == second_root {
+                Ok(())
+            } else {
+                Err(invalid())
+            };
+        }
+        let mut path = self.path.clone();
+        if self.first_size.is_power_of_two() {
+            path.insert(0, first_root.to_vec());
+        }
+        let (start, path) = path.split_first().ok_or_else(invalid)?;
+        let mut first = self.first_size - 1;
+        let mut second = self.second_size - 1;
+        shift_until(&mut first, &mut second, |first| first & 1 == 0);
+        let mut first_hash = start.clone();
+        let mut second_hash = start.clone();
+        for sibling in path {
+            if second == 0 {
+                return Err(invalid());
+            }
+            if first & 1 == 1 || first == second {
+                first_hash = node_hash(backend, sibling, &first_hash)?;
+                second_hash = node_hash(backend, sibling, &second_hash)?;
+                shift_until(&mut first, &mut second, |first| {
+                    first & 1 == 1 || first == 0
+                });
+            } else {
+                second_hash = node_hash(backend, &second_hash, sibling)?;
+            }
+            first >>= 1;
+            second >>= 1;
+        }
+        if second == 0 && first_hash == first_root && second_hash == second_root {
+            Ok(())
+        } else {
+            Err(invalid())
+        }
+    }
+}
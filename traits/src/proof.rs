@@ -0,0 +1,74 @@
+use tls_codec::{Deserialize, Serialize};
+
+use crate::backend::EidBackend;
+use crate::checkpoint::Checkpoint;
+use crate::member::Member;
+use crate::state::EidState;
+use crate::types::{EidError, EidId};
+
+/// # EidMembershipProof
+/// A compact proof that a [Member] is part of an EID in a given epoch, created by the member's client
+/// (see [EidClient::prove_membership](crate::client::EidClient::prove_membership)).
+/// It is sent over the wire and can be verified by third parties that don't hold the EID's transcript,
+/// but trust a [Checkpoint] of it.
+pub trait EidMembershipProof: Serialize + Deserialize {
+    /// Type of [Member](Self::MemberProvider) whose membership is proven.
+    type MemberProvider: Member;
+
+    /// Type of [EidState](Self::StateProvider) the proof can be checked against.
+    type StateProvider: EidState<
+        MemberProvider = Self::MemberProvider,
+        BackendProvider = Self::BackendProvider,
+    >;
+
+    /// Type of [EidBackend](Self::BackendProvider) this [EidMembershipProof](Self) uses.
+    type BackendProvider: EidBackend;
+
+    /// Get the [EidId] of the EID the proof claims membership in.
+    ///
+    /// returns: [EidId]
+    fn eid_id(&self) -> EidId;
+
+    /// Get the epoch the proof claims membership in.
+    ///
+    /// returns: [u64]
+    fn epoch(&self) -> u64;
+
+    /// Verify the proof against a trusted [Checkpoint] of the EID's transcript in the proof's epoch,
+    /// e.g. one the transcript operator signed (see [SignedCheckpoint::verify](crate::checkpoint::SignedCheckpoint::verify)).
+    /// Anyone can create an EID with the same [EidId] and epoch, so the checkpoint is the trust anchor:
+    /// the proof has to show the members the checkpoint commits to (see [Checkpoint::members_digest]).
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint`: The trusted [Checkpoint]
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[Self::MemberProvider], [EidError]> The [Member] whose membership is proven.
+    /// [EidError::InvalidMembershipProofError] if the proof is invalid or doesn't match the checkpoint.
+    ///
+    fn verify(
+        &self,
+        checkpoint: &Checkpoint,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError>;
+
+    /// Verify the proof against an [EidState] in the proof's epoch, e.g. the trusted state of a transcript
+    /// (see [EidTranscript::get_trusted_state](crate::transcript::EidTranscript::get_trusted_state)).
+    /// The proof has to show the same EID as the [EidState], not just the same [EidId] and epoch,
+    /// and the proven [Member] has to be a member of the [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `state`: The [Self::StateProvider]
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[Self::MemberProvider], [EidError]> The [Member] whose membership is proven.
+    /// [EidError::InvalidMembershipProofError] if the proof is invalid or doesn't match the state.
+    ///
+    fn verify_with_state(
+        &self,
+        state: &Self::StateProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError>;
+}
@@ -1,7 +1,7 @@
 use crate::backend::EidBackend;
-use crate::evolvement::Evolvement;
+use crate::evolvement::{Evolvement, EvolvementSummary, EvolvementVerdict};
 use crate::member::Member;
-use crate::types::EidError;
+use crate::types::{ChallengeResponse, EidError, EidId, MembershipEvent};
 
 /// # EidState
 /// The state of the EID. Each Client and the Transcript have their own [EidState]
@@ -22,13 +22,99 @@ pub trait EidState: Sized {
     /// * `evolvement`: An [Evolvement]
     /// * `backend`: The [Self::BackendProvider]
     ///
-    /// returns: Result<(), EidError> [EidError] if evolvement is invalid.
+    /// returns: Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> The changes to the members of the EID.
+    /// [EidError] if evolvement is invalid, belongs to a different EID (see [verify_eid](Self::verify_eid))
+    /// or doesn't apply to the current epoch (see [verify_epoch](Self::verify_epoch)).
     ///
     fn apply(
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError>;
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError>;
+
+    /// Check whether an [Evolvement] could be applied, without changing the [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: An [Evolvement]
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [EvolvementVerdict]
+    ///
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict;
+
+    /// Describe the change an [Evolvement] makes, without changing the [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: An [Evolvement] that applies to the state's epoch
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[EvolvementSummary]<[Self::MemberProvider]>, [EidError]> [EidError] if the evolvement is invalid.
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError>;
+
+    /// Get the epoch of the [EidState], i.e. the number of [Evolvement]s that changed it since the EID was created.
+    ///
+    /// returns: [u64]
+    fn epoch(&self) -> u64;
+
+    /// Get the [EidId] of the EID this [EidState] belongs to.
+    ///
+    /// returns: [EidId]
+    fn eid_id(&self) -> EidId;
+
+    /// Check that an [Evolvement] belongs to the EID of the [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: An [Evolvement]
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::WrongEidError] if the evolvement belongs to a different EID.
+    ///
+    fn verify_eid(&self, evolvement: &Self::EvolvementProvider) -> Result<(), EidError> {
+        if evolvement.eid_id() == self.eid_id() {
+            Ok(())
+        } else {
+            Err(EidError::WrongEidError(
+                "Evolvement belongs to a different EID".into(),
+            ))
+        }
+    }
+
+    /// Check that an [Evolvement] applies to the current epoch of the [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: An [Evolvement]
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::StaleEvolvementError] if the evolvement applies to a past epoch,
+    /// [EidError::FutureEvolvementError] if it applies to a future epoch.
+    ///
+    fn verify_epoch(&self, evolvement: &Self::EvolvementProvider) -> Result<(), EidError> {
+        let epoch = self.epoch();
+        let target_epoch = evolvement.target_epoch();
+        if target_epoch < epoch {
+            Err(EidError::StaleEvolvementError(format!(
+                "Evolvement applies to epoch {}, state is in epoch {}",
+                target_epoch, epoch
+            )))
+        } else if target_epoch > epoch {
+            Err(EidError::FutureEvolvementError(format!(
+                "Evolvement applies to epoch {}, state is in epoch {}",
+                target_epoch, epoch
+            )))
+        } else {
+            Ok(())
+        }
+    }
 
     /// Verify that a [Member] is part of the EID.
     ///
@@ -42,7 +128,41 @@ pub trait EidState: Sized {
         self.get_members().contains(member)
     }
 
+    /// Verify that a [ChallengeResponse] was signed by a current [Member] of the EID
+    /// (see [EidClient::sign_challenge](crate::client::EidClient::sign_challenge)).
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce`: The nonce the relying party challenged the member with
+    /// * `response`: The [ChallengeResponse]
+    /// * `backend`: The [Self::BackendProvider]
+    ///
+    /// returns: [Result]<[Self::MemberProvider], [EidError]> The [Member] that signed the challenge.
+    /// [EidError::InvalidChallengeResponseError] if the signer isn't a current member or the signature is invalid.
+    ///
+    fn verify_challenge(
+        &self,
+        nonce: &[u8],
+        response: &ChallengeResponse,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError>;
+
     /// Get all [Member]s of the EID.
     /// returns: [Vec]<[Self::MemberProvider]>
     fn get_members(&self) -> Vec<Self::MemberProvider>;
+
+    /// Get all [Member]s that were invited to the EID but haven't cross-signed their membership yet.
+    /// returns: [Vec]<[Self::MemberProvider]>
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider>;
+
+    /// Get all pending [Member]s whose invitation has expired (see [InvitationExpiry]).
+    /// Applying or validating a commit of a pending member whose invitation expired by epoch fails.
+    /// Invitations that expire at a time are only reported here, since the time isn't part of the evolvement.
+    ///
+    /// # Arguments
+    ///
+    /// * `now`: The current time, used for invitations that expire at a time
+    ///
+    /// returns: [Vec]<[Self::MemberProvider]>
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider>;
 }
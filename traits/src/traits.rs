@@ -1,7 +1,11 @@
 pub mod backend;
+pub mod buffer;
+pub mod checkpoint;
 pub mod client;
 pub mod evolvement;
 pub mod member;
+pub mod merkle;
+pub mod proof;
 pub mod state;
 pub mod transcript;
 pub mod types;
@@ -1,10 +1,12 @@
-use tls_codec::{Deserialize, Serialize};
+use tls_codec::{Deserialize, Serialize, TlsDeserialize, TlsSerialize, TlsSize};
 
 use crate::backend::EidBackend;
-use crate::evolvement::Evolvement;
+use crate::checkpoint::{Checkpoint, SignedCheckpoint};
+use crate::evolvement::{Evolvement, EvolvementSummary, EvolvementVerdict};
 use crate::member::Member;
+use crate::merkle::{root, ConsistencyProof, InclusionProof};
 use crate::state::EidState;
-use crate::types::EidError;
+use crate::types::{ChallengeResponse, EidError, EidId, MembershipEvent, MembershipRecord};
 
 /// # EidTranscript
 /// The Public Transcript of an EID. The Transcript holds a trusted [EidState] and a log of [Evolvement]s.
@@ -24,6 +26,7 @@ pub trait EidTranscript {
     type StateProvider: EidState<
         EvolvementProvider = Self::EvolvementProvider,
         MemberProvider = Self::MemberProvider,
+        BackendProvider = Self::BackendProvider,
     >;
     /// Creates a new log from a trusted [EidState] and a [Vec] of [Evolvement]s
     /// that happened after the trusted [EidState].
@@ -41,6 +44,35 @@ pub trait EidTranscript {
         log: Vec<Self::EvolvementProvider>,
         backend: &Self::BackendProvider,
     ) -> Result<Self, EidError>
+    where
+        Self: Sized,
+    {
+        Self::new_with_prefix(trusted_state, None, log, backend)
+    }
+
+    /// Creates a new log from a trusted [EidState], the [LogPrefix] that was pruned before it
+    /// and a [Vec] of [Evolvement]s that happened after the trusted [EidState],
+    /// e.g. to rebuild a transcript after it was [compacted](Self::compact).
+    /// The hash chain continues from the head of the prefix, so the rebuilt transcript has the same
+    /// [head](Self::head_hash) and [log size](Self::log_size) as the compacted one.
+    /// The prefix can't be checked against the trusted [EidState], so it has to come from the same source,
+    /// e.g. the compacted transcript (see [log_prefix](Self::log_prefix)).
+    ///
+    /// # Arguments
+    ///
+    /// * `trusted_state`: The trusted [EidState]
+    /// * `prefix`: The pruned [LogPrefix], [None] if the log starts with the trusted [EidState]
+    /// * `log`: The [Evolvement]s that happened after the trusted [EidState]
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self], [EidError]> [EidError] if trusted_state or log is invalid.
+    ///
+    fn new_with_prefix(
+        trusted_state: Self::StateProvider,
+        prefix: Option<LogPrefix>,
+        log: Vec<Self::EvolvementProvider>,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self, EidError>
     where
         Self: Sized;
 
@@ -51,48 +83,519 @@ pub trait EidTranscript {
     /// * `evolvement`:
     /// * `backend`:
     ///
-    /// returns: Result<(), EidError>
+    /// returns: Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError> The changes to the members of the EID.
+    /// [EidError::StaleEvolvementError] or [EidError::FutureEvolvementError]
+    /// if the evolvement doesn't apply to the epoch of the current state.
+    /// [EidError::WrongEidError] if the evolvement belongs to a different EID.
     ///
     fn evolve(
         &mut self,
         evolvement: Self::EvolvementProvider,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError>;
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError>;
+
+    /// Check whether an [Evolvement](Self::EvolvementProvider) could be applied to the transcript's current state, without changing it.
+    /// Use this e.g. to check an evolvement before distributing it to the clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](Self::EvolvementProvider)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [EvolvementVerdict]
+    ///
+    fn validate(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> EvolvementVerdict;
+
+    /// Describe the change an [Evolvement](Self::EvolvementProvider) makes to the transcript's current state, without changing it.
+    /// Describe each evolvement before evolving to build an audit trail of member changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](Self::EvolvementProvider), which applies to the current state's epoch
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[EvolvementSummary]<[Self::MemberProvider]>, [EidError]> [EidError] if the evolvement is invalid.
+    fn describe(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<EvolvementSummary<Self::MemberProvider>, EidError>;
 
     /// Evolve the transcript's current state by calling [evolve](Self::evolve)
     /// for each [Evolvement](Self::EvolvementProvider) in a [Vec] of [Evolvement](Self::EvolvementProvider)s.
+    /// The batch is atomic: if any evolvement fails, the current state and the log are rolled back to their state before the batch.
     ///
     /// # Arguments
     ///
     /// * `evolvements`: The [Vec]<[Self::EvolvementProvider]>
     /// * `backend`: The [Self::BackendProvider]
     ///
-    /// returns: [Result]<[()], [EidError]>) [EidError] If any [Self::EvolvementProvider] is invalid.
+    /// returns: [Result]<[Vec]<[MembershipEvent]<[Self::MemberProvider]>>, [EidError]>) The changes to the members of the EID, in the order of the evolvements.
+    /// [EidError] If any [Self::EvolvementProvider] is invalid.
     ///
     fn batch_evolve(
         &mut self,
         evolvements: Vec<Self::EvolvementProvider>,
         backend: &Self::BackendProvider,
-    ) -> Result<(), EidError> {
-        for evolvement in evolvements.iter() {
-            self.evolve(evolvement.clone(), backend)?;
-        }
-        Ok(())
-    }
+    ) -> Result<Vec<MembershipEvent<Self::MemberProvider>>, EidError>;
     /// Get the [Evolvement]s that happened after the trusted [EidState].
     ///
     /// returns: [Vec]<[Self::EvolvementProvider]>
     fn log(&self) -> Vec<Self::EvolvementProvider>;
 
+    /// Get the [LogPrefix] that was pruned from the log when the transcript was [compacted](Self::compact).
+    ///
+    /// returns: [Option]<[LogPrefix]> [None] if the transcript was never compacted.
+    fn log_prefix(&self) -> Option<LogPrefix>;
+
+    /// Get the number of [Evolvement]s that were appended to the log, including the pruned [LogPrefix].
+    ///
+    /// returns: [u64]
+    fn log_size(&self) -> u64 {
+        self.log_prefix().map_or(0, |prefix| prefix.size) + self.log().len() as u64
+    }
+
+    /// Get the head of the hash chain over the log.
+    /// Each [Evolvement](Self::EvolvementProvider) is chained to the previous head when it is appended to the log,
+    /// starting with a head that commits to the trusted [EidState] (see [LogHashInput]).
+    /// Anyone who learned a head can detect if entries up to it were changed, reordered or dropped.
+    ///
+    /// returns: [Vec]<[u8]>
+    fn head_hash(&self) -> Vec<u8>;
+
+    /// Get the head of the hash chain after the first `log_size` entries of the log, counting the pruned [LogPrefix].
+    ///
+    /// # Arguments
+    ///
+    /// * `log_size`: The number of entries (see [log_size](Self::log_size))
+    ///
+    /// returns: [Option]<[Vec]<[u8]>> [None] if the log has less than `log_size` entries
+    /// or the head was pruned when the transcript was [compacted](Self::compact).
+    fn head_hash_at(&self, log_size: u64) -> Option<Vec<u8>>;
+
+    /// Verify the log by replaying it from the trusted [EidState] and recomputing the hash chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidLogError] if an entry can't be applied,
+    /// doesn't match the hash chain or the replayed state doesn't match the current state.
+    ///
+    fn verify_log(&self, backend: &Self::BackendProvider) -> Result<(), EidError>;
+
+    /// Compact the transcript: the [EidState] at epoch `up_to_epoch` becomes the new trusted [EidState]
+    /// and the [Evolvement]s that led to it are pruned from the log.
+    /// The transcript keeps the size and head of the pruned [LogPrefix], so the hash chain continues
+    /// and checkpoints of later logs stay comparable (see [new_with_prefix](Self::new_with_prefix)).
+    /// The Merkle tree starts over with the remaining log, so [roots](Self::log_root) and
+    /// [consistency proofs](Self::prove_consistency) are no longer available for logs that end inside the pruned prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `up_to_epoch`: The epoch of the new trusted [EidState]
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Vec]<[Self::EvolvementProvider]>, [EidError]> The pruned [Evolvement]s, e.g. to archive them.
+    /// [EidError::CompactTranscriptError] if the log has no [EidState] at epoch `up_to_epoch`.
+    fn compact(
+        &mut self,
+        up_to_epoch: u64,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<Self::EvolvementProvider>, EidError>;
+
+    /// Get the hash of an [Evolvement](Self::EvolvementProvider) as a leaf of the Merkle tree over the log
+    /// (see [merkle](crate::merkle)).
+    /// Auditors use it to verify an [InclusionProof] without holding the transcript.
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](Self::EvolvementProvider)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    fn leaf_hash(
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<u8>, EidError>;
+
+    /// Get the root of the Merkle tree over the first `size` entries of the log.
+    /// Sizes count the entries of the [LogPrefix] like [log_size](Self::log_size) does,
+    /// but the Merkle tree of a [compacted](Self::compact) transcript only covers the remaining log.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: The number of entries, at most the [size](Self::log_size) of the log
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]> [EidError::InvalidMerkleProofError] if the log has less than `size` entries
+    /// or the last of them was pruned.
+    fn log_root(&self, size: u64, backend: &Self::BackendProvider) -> Result<Vec<u8>, EidError> {
+        let pruned = pruned_size(self, size)?;
+        let leaves = log_leaves(self, backend)?;
+        if size - pruned > leaves.len() as u64 {
+            return Err(EidError::InvalidMerkleProofError(format!(
+                "The log only has {} entries",
+                pruned + leaves.len() as u64
+            )));
+        }
+        root(backend, &leaves[..(size - pruned) as usize])
+    }
+
+    /// Prove that an [Evolvement](Self::EvolvementProvider) is part of the log.
+    /// The proof is verified against the [root](Self::log_root) of the whole log (see [InclusionProof::verify]).
+    ///
+    /// # Arguments
+    ///
+    /// * `evolvement`: The [Evolvement](Self::EvolvementProvider)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[InclusionProof], [EidError]> [EidError::InvalidMerkleProofError] if the evolvement isn't part of the log.
+    fn prove_inclusion(
+        &self,
+        evolvement: &Self::EvolvementProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<InclusionProof, EidError> {
+        let leaf_index = self
+            .log()
+            .iter()
+            .position(|entry| entry == evolvement)
+            .ok_or_else(|| {
+                EidError::InvalidMerkleProofError("Evolvement is not part of the log".into())
+            })?;
+        InclusionProof::new(backend, &log_leaves(self, backend)?, leaf_index)
+    }
+
+    /// Prove that the log extends the log with the first `first_size` entries.
+    /// The proof is verified against the [roots](Self::log_root) of both logs (see [ConsistencyProof::verify]).
+    ///
+    /// # Arguments
+    ///
+    /// * `first_size`: The [size](Self::log_size) of the earlier log
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[ConsistencyProof], [EidError]> [EidError::InvalidMerkleProofError] if `first_size` is 0,
+    /// larger than the size of the log or the last of the first `first_size` entries was pruned.
+    fn prove_consistency(
+        &self,
+        first_size: u64,
+        backend: &Self::BackendProvider,
+    ) -> Result<ConsistencyProof, EidError> {
+        let pruned = pruned_size(self, first_size)?;
+        ConsistencyProof::new(
+            backend,
+            &log_leaves(self, backend)?,
+            (first_size - pruned) as usize,
+        )
+    }
+
+    /// Get the digest of the [Member]s of the transcript's current [EidState]
+    /// (see [members_digest](crate::checkpoint::members_digest)).
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    fn members_digest(&self, backend: &Self::BackendProvider) -> Result<Vec<u8>, EidError>;
+
+    /// Get a [Checkpoint] of the transcript's current view.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Checkpoint], [EidError]>
+    fn checkpoint(&self, backend: &Self::BackendProvider) -> Result<Checkpoint, EidError> {
+        Ok(Checkpoint {
+            eid_id: self.eid_id(),
+            epoch: self.epoch(),
+            log_size: self.log_size(),
+            head_hash: self.head_hash(),
+            members_digest: self.members_digest(backend)?,
+        })
+    }
+
+    /// Issue a [SignedCheckpoint] of the transcript's current view with the key of the transcript operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_key`: The signing key of the operator (see [EidBackend::generate_signing_key])
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[SignedCheckpoint], [EidError]>
+    fn sign_checkpoint(
+        &self,
+        operator_key: &<Self::BackendProvider as EidBackend>::SigningKeyProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<SignedCheckpoint, EidError> {
+        self.checkpoint(backend)?.sign(operator_key, backend)
+    }
+
+    /// Verify a gossiped [SignedCheckpoint] and compare it with the transcript's own view.
+    /// A checkpoint of a log that is as long as the transcript's log must match the transcript's [Checkpoint].
+    /// A checkpoint of a shorter log must match the [head](Self::head_hash_at) of the transcript's log at its size.
+    /// A checkpoint of a longer log can't be compared until the transcript has caught up,
+    /// and neither can a checkpoint whose head was pruned when the transcript was [compacted](Self::compact).
+    ///
+    /// # Arguments
+    ///
+    /// * `signed_checkpoint`: The [SignedCheckpoint]
+    /// * `operator_public_key`: The public key of the transcript operator (see [EidBackend::public_key])
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<(), [EidError]> [EidError::InvalidCheckpointError] if the signature is invalid,
+    /// [EidError::WrongEidError] if the checkpoint belongs to another EID,
+    /// [EidError::IncomparableCheckpointError] if the checkpoint can't be compared with the transcript's view and
+    /// [EidError::SplitViewError] if the checkpoint contradicts the transcript's view.
+    fn verify_checkpoint(
+        &self,
+        signed_checkpoint: &SignedCheckpoint,
+        operator_public_key: &[u8],
+        backend: &Self::BackendProvider,
+    ) -> Result<(), EidError> {
+        let checkpoint = signed_checkpoint.verify(operator_public_key, backend)?;
+        if checkpoint.eid_id != self.eid_id() {
+            return Err(EidError::WrongEidError(
+                "The checkpoint belongs to another EID".into(),
+            ));
+        }
+        let log_size = self.log_size();
+        let consistent = if checkpoint.log_size == log_size {
+            checkpoint == &self.checkpoint(backend)?
+        } else if checkpoint.log_size < log_size {
+            let head = self.head_hash_at(checkpoint.log_size).ok_or_else(|| {
+                EidError::IncomparableCheckpointError(format!(
+                    "The head of the log with {} entries was pruned when the transcript was compacted",
+                    checkpoint.log_size
+                ))
+            })?;
+            head == checkpoint.head_hash
+        } else {
+            return Err(EidError::IncomparableCheckpointError(format!(
+                "The checkpoint of the log with {} entries is ahead of the transcript's log with {} entries",
+                checkpoint.log_size, log_size
+            )));
+        };
+        if consistent {
+            Ok(())
+        } else {
+            Err(EidError::SplitViewError(format!(
+                "The checkpoint of the log with {} entries contradicts the transcript",
+                checkpoint.log_size
+            )))
+        }
+    }
+
+    /// Get mutable access to the log, to simulate a transcript host that tampers with it.
+    #[cfg(feature = "test")]
+    fn log_mut(&mut self) -> &mut Vec<Self::EvolvementProvider>;
+
     /// Get all [Member]s of the EID.
     ///
     /// returns: [Vec]<[Self::MemberProvider]>
     fn get_members(&self) -> Vec<Self::MemberProvider>;
 
+    /// Get all [Member]s of the EID at `epoch`, by replaying the log from the trusted [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch`: The epoch, between the epoch of the trusted [EidState] and the current [epoch](Self::epoch)
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Vec]<[Self::MemberProvider]>, [EidError]> [EidError::UnknownEpochError] if the log has no [EidState] at `epoch`,
+    /// e.g. because it was pruned when the transcript was [compacted](Self::compact).
+    fn get_members_at(
+        &self,
+        epoch: u64,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<Self::MemberProvider>, EidError> {
+        let mut state = self.get_trusted_state()?;
+        let mut log = self.log().into_iter();
+        while state.epoch() != epoch {
+            let evolvement = log.next().ok_or_else(|| {
+                EidError::UnknownEpochError(format!("The log has no state at epoch {}", epoch))
+            })?;
+            state.apply(evolvement, backend)?;
+        }
+        Ok(state.get_members())
+    }
+
+    /// Get the history of a [Member] since the trusted [EidState]: the epochs in which it was added,
+    /// cross-signed its membership, updated its key material and was removed, by replaying the log.
+    ///
+    /// # Arguments
+    ///
+    /// * `member`: The [Member]
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Vec]<[MembershipRecord]<[Self::MemberProvider]>>, [EidError]> The [MembershipRecord]s in the order of the log.
+    fn member_history(
+        &self,
+        member: &Self::MemberProvider,
+        backend: &Self::BackendProvider,
+    ) -> Result<Vec<MembershipRecord<Self::MemberProvider>>, EidError> {
+        let mut state = self.get_trusted_state()?;
+        let mut history = vec![];
+        for evolvement in self.log() {
+            let events = state.apply(evolvement, backend)?;
+            let epoch = state.epoch();
+            history.extend(
+                events
+                    .into_iter()
+                    .filter(|event| event.member() == member)
+                    .map(|event| MembershipRecord { epoch, event }),
+            );
+        }
+        Ok(history)
+    }
+
+    /// Get all [Member]s that were invited to the EID but haven't cross-signed their membership yet.
+    ///
+    /// returns: [Vec]<[Self::MemberProvider]>
+    fn get_pending_members(&self) -> Vec<Self::MemberProvider>;
+
+    /// Get all pending [Member]s whose invitation has expired (see [EidState::get_expired_members]).
+    ///
+    /// # Arguments
+    ///
+    /// * `now`: The current time, used for invitations that expire at a time
+    ///
+    /// returns: [Vec]<[Self::MemberProvider]>
+    fn get_expired_members(&self, now: Option<u64>) -> Vec<Self::MemberProvider>;
+
+    /// Verify that a [ChallengeResponse] was signed by a member of the transcript's current [EidState]
+    /// (see [EidState::verify_challenge]).
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce`: The nonce the relying party challenged the member with
+    /// * `response`: The [ChallengeResponse]
+    /// * `backend`: The [Backend](Self::BackendProvider)
+    ///
+    /// returns: [Result]<[Self::MemberProvider], [EidError]> The [Member] that signed the challenge.
+    fn verify_challenge(
+        &self,
+        nonce: &[u8],
+        response: &ChallengeResponse,
+        backend: &Self::BackendProvider,
+    ) -> Result<Self::MemberProvider, EidError>;
+
+    /// Get the epoch of the transcript's current [EidState] (see [EidState::epoch]).
+    ///
+    /// returns: [u64]
+    fn epoch(&self) -> u64;
+
+    /// Get the [EidId] of the EID the transcript belongs to (see [EidState::eid_id]).
+    ///
+    /// returns: [EidId]
+    fn eid_id(&self) -> EidId;
+
     /// Get the trusted [EidState] that is saved to the [EidTranscript](Self)
     fn get_trusted_state(&self) -> Result<Self::StateProvider, EidError>;
 }
 
+/// Get the leaves of the Merkle tree over the log of a transcript.
+fn log_leaves<T: EidTranscript + ?Sized>(
+    transcript: &T,
+    backend: &T::BackendProvider,
+) -> Result<Vec<Vec<u8>>, EidError> {
+    transcript
+        .log()
+        .iter()
+        .map(|evolvement| T::leaf_hash(evolvement, backend))
+        .collect()
+}
+
+/// The number of entries pruned from the log of `transcript` before the Merkle tree,
+/// if a log with `size` entries ends after them.
+fn pruned_size<T: EidTranscript + ?Sized>(transcript: &T, size: u64) -> Result<u64, EidError> {
+    match transcript.log_prefix() {
+        Some(prefix) if size <= prefix.size => Err(EidError::InvalidMerkleProofError(format!(
+            "The first {} entries of the log were pruned when the transcript was compacted",
+            prefix.size
+        ))),
+        Some(prefix) => Ok(prefix.size),
+        None => Ok(0),
+    }
+}
+
+/// # Log Prefix
+/// The part of the log that was pruned when an [EidTranscript] was [compacted](EidTranscript::compact).
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct LogPrefix {
+    /// The number of pruned [Evolvement]s.
+    pub size: u64,
+    /// The head of the hash chain after the last pruned [Evolvement].
+    pub head_hash: Vec<u8>,
+}
+
+/// # Log Hash Input
+/// The content that is hashed to build the hash chain over the log of an [EidTranscript] (see [EidTranscript::head_hash]).
+/// The first head commits to the [EidId], the epoch and the members of the trusted [EidState],
+/// so that transcripts that start with different states of the same EID and epoch have different heads.
+/// Every following head commits to the previous head and the epoch and serialization of the next [Evolvement].
+#[derive(TlsSerialize, TlsSize)]
+pub struct LogHashInput {
+    label: Vec<u8>,
+    previous_head: Vec<u8>,
+    epoch: u64,
+    content: Vec<u8>,
+    members_digest: Vec<u8>,
+}
+
+impl LogHashInput {
+    /// Get the input of the first head of a log that starts with a trusted [EidState].
+    ///
+    /// # Arguments
+    ///
+    /// * `trusted_state`: The trusted [EidState]
+    /// * `members_digest`: The digest of the members of the trusted [EidState]
+    ///   (see [members_digest](crate::checkpoint::members_digest))
+    ///
+    /// returns: [LogHashInput]
+    pub fn root<S: EidState>(trusted_state: &S, members_digest: Vec<u8>) -> Self {
+        Self {
+            label: b"EID log root".to_vec(),
+            previous_head: vec![],
+            epoch: trusted_state.epoch(),
+            content: trusted_state.eid_id().as_slice().to_vec(),
+            members_digest,
+        }
+    }
+
+    /// Get the input of the head after an [Evolvement] was appended to the log.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous_head`: The head before the [Evolvement] was appended
+    /// * `evolvement`: The appended [Evolvement]
+    /// * `serialized`: The serialization of the [Evolvement]
+    ///
+    /// returns: [LogHashInput]
+    pub fn entry<E: Evolvement>(previous_head: &[u8], evolvement: &E, serialized: Vec<u8>) -> Self {
+        Self {
+            label: b"EID log entry".to_vec(),
+            previous_head: previous_head.to_vec(),
+            epoch: evolvement.target_epoch(),
+            content: serialized,
+            members_digest: vec![],
+        }
+    }
+
+    /// Serialize the input, so that it can be hashed.
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EidError> {
+        self.tls_serialize_detached()
+            .map_err(|e| EidError::SerializationError(e.to_string()))
+    }
+}
+
 /// # EID Exported Transcript State
 /// State that is exported by the client and sent over the wire. The only function it needs
 /// to implement is the conversion to a transcript state.
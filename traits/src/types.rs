@@ -3,6 +3,8 @@
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
+use tls_codec::{Serialize, TlsDeserialize, TlsSerialize, TlsSize};
+
 /// Errors related to EID
 #[derive(Debug)]
 pub enum EidError {
@@ -12,17 +14,38 @@ pub enum EidError {
     AddMemberError(String),
     UpdateMemberError(String),
     RemoveMemberError(String),
+    CommitError(String),
 
     InvalidMemberError(String),
     InvalidEvolvementError(String),
+    StaleEvolvementError(String),
+    FutureEvolvementError(String),
+    ConflictingEvolvementError(String),
+    WrongEidError(String),
     InvalidInvitationError(String),
+    InactiveClientError(String),
+    InvalidMembershipProofError(String),
+    InvalidChallengeResponseError(String),
+    InvalidLogError(String),
+    InvalidMerkleProofError(String),
+    InvalidCheckpointError(String),
+    IncomparableCheckpointError(String),
+    SplitViewError(String),
+    UnknownEpochError(String),
 
     CreateTranscriptStateError(String),
+    CompactTranscriptError(String),
     ExportTranscriptStateError(String),
     ImportTranscriptStateError(String),
 
+    ExportClientStateError(String),
+    RestoreClientStateError(String),
+
     CreateClientError(String),
     CreateCredentialError(String),
+    UnsupportedCiphersuiteError(String),
+    InvalidConfigError(String),
+    CryptoError(String),
 }
 
 impl Display for EidError {
@@ -35,15 +58,180 @@ impl Display for EidError {
             | EidError::RemoveMemberError(s)
             | EidError::InvalidMemberError(s)
             | EidError::InvalidEvolvementError(s)
+            | EidError::StaleEvolvementError(s)
+            | EidError::FutureEvolvementError(s)
+            | EidError::ConflictingEvolvementError(s)
+            | EidError::WrongEidError(s)
             | EidError::InvalidInvitationError(s)
+            | EidError::InactiveClientError(s)
+            | EidError::InvalidMembershipProofError(s)
+            | EidError::InvalidChallengeResponseError(s)
+            | EidError::InvalidLogError(s)
+            | EidError::InvalidMerkleProofError(s)
+            | EidError::InvalidCheckpointError(s)
+            | EidError::IncomparableCheckpointError(s)
+            | EidError::SplitViewError(s)
+            | EidError::UnknownEpochError(s)
+            | EidError::CommitError(s)
             | EidError::CreateTranscriptStateError(s)
+            | EidError::CompactTranscriptError(s)
             | EidError::ExportTranscriptStateError(s)
             | EidError::ImportTranscriptStateError(s)
+            | EidError::ExportClientStateError(s)
+            | EidError::RestoreClientStateError(s)
             | EidError::CreateClientError(s)
-            | EidError::CreateCredentialError(s) => s,
+            | EidError::CreateCredentialError(s)
+            | EidError::UnsupportedCiphersuiteError(s)
+            | EidError::InvalidConfigError(s)
+            | EidError::CryptoError(s) => s,
         };
         write!(f, "{:?}", error_string)
     }
 }
 
 impl Error for EidError {}
+
+/// # EID Id
+/// The identifier of an EID. It is chosen when the EID is created and never changes,
+/// so that evolvements, states and transcripts can be matched to the EID they belong to.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct EidId(Vec<u8>);
+
+impl EidId {
+    pub fn new(id: Vec<u8>) -> Self {
+        Self(id)
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for EidId {
+    fn from(id: Vec<u8>) -> Self {
+        Self(id)
+    }
+}
+
+/// # EID Change
+/// The change an [Evolvement](crate::evolvement::Evolvement) makes to the members of an EID.
+/// Used to create a change again after its evolvement lost a conflict (see [EidClient::resolve_conflict](crate::client::EidClient::resolve_conflict)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EidChange<M> {
+    /// Members that are added to the EID.
+    pub added: Vec<M>,
+    /// Members that are removed from the EID.
+    pub removed: Vec<M>,
+    /// True if the client updates its own key material without adding or removing members.
+    pub self_update: bool,
+}
+
+/// # Membership Event
+/// A change to a single member of an EID, reported when an [Evolvement](crate::evolvement::Evolvement) is applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MembershipEvent<M> {
+    /// The member was invited to the EID and still needs to cross-sign its membership.
+    MemberAdded(M),
+    /// The member cross-signed its membership and is now part of the EID.
+    MemberCrossSigned(M),
+    /// The member updated its key material.
+    MemberUpdatedKey(M),
+    /// The member was removed from the EID.
+    MemberRemoved(M),
+}
+
+impl<M> MembershipEvent<M> {
+    /// Get the member the event is about.
+    pub fn member(&self) -> &M {
+        match self {
+            Self::MemberAdded(member)
+            | Self::MemberCrossSigned(member)
+            | Self::MemberUpdatedKey(member)
+            | Self::MemberRemoved(member) => member,
+        }
+    }
+}
+
+/// # Membership Record
+/// A [MembershipEvent] and the epoch the EID entered with it
+/// (see [EidTranscript::member_history](crate::transcript::EidTranscript::member_history)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembershipRecord<M> {
+    pub epoch: u64,
+    pub event: MembershipEvent<M>,
+}
+
+/// # Invitation Expiry
+/// When the invitation of a member expires if the member hasn't cross-signed its membership until then.
+/// Any client can remove members with expired invitations (see [EidClient::prune_expired_members](crate::client::EidClient::prune_expired_members)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+#[repr(u8)]
+pub enum InvitationExpiry {
+    /// The invitation expires once the EID reaches this epoch.
+    /// A member can't cross-sign its membership after that.
+    Epoch(u64),
+    /// The invitation expires at this time, e.g. a unix timestamp. The current time is always supplied by the caller.
+    /// Clients and transcripts don't share a clock, so this expiry isn't enforced when evolvements are applied or validated.
+    /// Members whose invitation expired at a time are only reported (see [EidState::get_expired_members](crate::state::EidState::get_expired_members))
+    /// and have to be removed explicitly.
+    Time(u64),
+}
+
+impl InvitationExpiry {
+    /// True if the invitation has expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch`: The current epoch of the EID
+    /// * `now`: The current time, if known. Time based invitations never expire without it.
+    ///
+    /// returns: [bool]
+    pub fn is_expired(&self, epoch: u64, now: Option<u64>) -> bool {
+        match self {
+            Self::Epoch(expiry_epoch) => epoch >= *expiry_epoch,
+            Self::Time(expiry_time) => now.map_or(false, |now| now >= *expiry_time),
+        }
+    }
+}
+
+/// # Challenge Response
+/// The signature of a member over a nonce chosen by a relying party
+/// (see [EidClient::sign_challenge](crate::client::EidClient::sign_challenge)).
+/// It proves that the sender controls the key of a current member of the EID
+/// (see [EidState::verify_challenge](crate::state::EidState::verify_challenge)).
+#[derive(Debug, Clone, PartialEq, Eq, TlsSerialize, TlsDeserialize, TlsSize)]
+pub struct ChallengeResponse {
+    /// The signature key of the member that signed the challenge.
+    pub signature_key: Vec<u8>,
+    /// The signature over the [signed content](Self::signed_content).
+    pub signature: Vec<u8>,
+}
+
+/// The content a member signs to respond to a challenge.
+#[derive(TlsSerialize, TlsSize)]
+struct ChallengeContent {
+    label: Vec<u8>,
+    eid_id: EidId,
+    nonce: Vec<u8>,
+}
+
+impl ChallengeResponse {
+    /// Get the content a member signs to respond to the challenge `nonce` of a relying party.
+    /// It is bound to the EID and labeled, so that it can't be confused with signatures for other purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `eid_id`: The [EidId] of the EID the member is part of
+    /// * `nonce`: The nonce chosen by the relying party
+    ///
+    /// returns: [Result]<[Vec]<[u8]>, [EidError]>
+    pub fn signed_content(eid_id: &EidId, nonce: &[u8]) -> Result<Vec<u8>, EidError> {
+        ChallengeContent {
+            label: b"EID challenge".to_vec(),
+            eid_id: eid_id.clone(),
+            nonce: nonce.to_vec(),
+        }
+        .tls_serialize_detached()
+        .map_err(|e| EidError::SerializationError(e.to_string()))
+    }
+}